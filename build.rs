@@ -0,0 +1,309 @@
+//! Registers every solver in the tree.
+//!
+//! Each year lives in a directory named `dYYYY`, under `src/` or `src/archive/`. Every `dayNN.rs` in it which exports
+//! a solver (with `export_solver!(solver, ...)` or `generate_solver!(solver, ...)`) is compiled into that year's
+//! module and added to the crate's list of solvers; any other `.rs` file is compiled as a module shared by the year's
+//! days. Adding a day is a matter of creating its file, and removing one a matter of deleting it.
+//!
+//! A year is only compiled when its `yYYYY` Cargo feature is enabled.
+//!
+//! Example inputs are found the same way: every `dayNN.exampleN.txt` next to a registered day's input (in
+//! `inputs/YYYY/` or `inputs/archive/YYYY/`) becomes a test checking the day's solver against the answers recorded
+//! beside it in `dayNN.exampleN.answers`, with the solver parameters given in `dayNN.exampleN.params`, if any.
+
+use core::fmt::Write as _;
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+};
+
+/// The directories, relative to the manifest, which hold year directories.
+const YEAR_PARENTS: &[&str] = &["src", "src/archive"];
+
+/// The directories, relative to the manifest, which hold each year's inputs.
+const INPUT_PARENTS: &[&str] = &["inputs", "inputs/archive"];
+
+struct YearDir {
+	year: u16,
+	dir: PathBuf,
+	/// Days whose modules export a solver, with their module names.
+	days: Vec<(u8, String)>,
+	/// Days with a module which doesn't export a solver; these are not compiled.
+	unregistered: Vec<u8>,
+	/// Other modules in the year's directory, shared by its days.
+	shared: Vec<String>,
+	/// Example inputs for the days which export a solver.
+	examples: Vec<Example>,
+}
+
+struct Example {
+	day: u8,
+	/// The part of the file name after the day, e.g. `example1`.
+	name: String,
+	input: PathBuf,
+	/// The recorded answers, if there is a file for them.
+	answers: Option<PathBuf>,
+	/// The solver parameters to use, if there is a file for them.
+	params: Option<PathBuf>,
+	/// Whether the input is empty, as it is when a day has just been scaffolded.
+	empty: bool,
+}
+
+fn main() {
+	let manifest_dir =
+		PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by Cargo"));
+	let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by Cargo"));
+
+	let mut years = Vec::new();
+
+	for parent in YEAR_PARENTS {
+		println!("cargo::rerun-if-changed={parent}");
+
+		let entries = fs::read_dir(manifest_dir.join(parent))
+			.unwrap_or_else(|error| panic!("could not read {parent}: {error}"));
+
+		for entry in entries.map(|entry| entry.expect("could not read directory entry")) {
+			let name = entry.file_name().to_string_lossy().into_owned();
+			let Some(year) = name
+				.strip_prefix('d')
+				.and_then(|year| year.parse::<u16>().ok())
+			else {
+				continue;
+			};
+
+			if entry.path().is_dir() && env::var_os(format!("CARGO_FEATURE_Y{year}")).is_some() {
+				years.push(scan_year(year, &entry.path()));
+			}
+		}
+	}
+
+	years.sort_by_key(|year| year.year);
+
+	for parent in INPUT_PARENTS {
+		let inputs = manifest_dir.join(parent);
+		if !inputs.is_dir() {
+			continue;
+		}
+
+		println!("cargo::rerun-if-changed={parent}");
+
+		for year in &mut years {
+			scan_examples(year, &inputs.join(year.year.to_string()));
+		}
+	}
+
+	fs::write(out_dir.join("solvers.rs"), generate(&years)).expect("could not write solvers.rs");
+	fs::write(out_dir.join("examples.rs"), generate_examples(&years))
+		.expect("could not write examples.rs");
+}
+
+fn scan_year(year: u16, dir: &Path) -> YearDir {
+	let mut scanned = YearDir {
+		year,
+		dir: dir.to_path_buf(),
+		days: Vec::new(),
+		unregistered: Vec::new(),
+		shared: Vec::new(),
+		examples: Vec::new(),
+	};
+
+	let entries =
+		fs::read_dir(dir).unwrap_or_else(|error| panic!("could not read {}: {error}", dir.display()));
+
+	for path in entries.map(|entry| entry.expect("could not read directory entry").path()) {
+		if path.extension().is_none_or(|extension| extension != "rs") || !path.is_file() {
+			continue;
+		}
+
+		let stem = path
+			.file_stem()
+			.expect("a file with an extension has a stem")
+			.to_string_lossy()
+			.into_owned();
+
+		match stem
+			.strip_prefix("day")
+			.and_then(|day| day.parse::<u8>().ok())
+		{
+			Some(day) => {
+				let source = fs::read_to_string(&path)
+					.unwrap_or_else(|error| panic!("could not read {}: {error}", path.display()));
+
+				if exports_solver(&source) {
+					scanned.days.push((day, stem));
+				} else {
+					scanned.unregistered.push(day);
+				}
+			}
+			// A leftover `mod.rs` can't be a module of its own.
+			None if stem == "mod" => {}
+			None => scanned.shared.push(stem),
+		}
+	}
+
+	scanned.days.sort_unstable();
+	scanned.unregistered.sort_unstable();
+	scanned.shared.sort_unstable();
+
+	scanned
+}
+
+fn scan_examples(year: &mut YearDir, dir: &Path) {
+	let Ok(entries) = fs::read_dir(dir) else {
+		return;
+	};
+
+	for path in entries.map(|entry| entry.expect("could not read directory entry").path()) {
+		let file_name = path
+			.file_name()
+			.expect("a directory entry has a file name")
+			.to_string_lossy()
+			.into_owned();
+
+		let Some((day, name)) = file_name
+			.strip_suffix(".txt")
+			.and_then(|stem| stem.strip_prefix("day"))
+			.and_then(|stem| stem.split_once('.'))
+		else {
+			continue;
+		};
+		let Ok(day) = day.parse::<u8>() else {
+			continue;
+		};
+
+		if !name.starts_with("example") || !year.days.iter().any(|&(solved, _)| solved == day) {
+			continue;
+		}
+
+		let answers = path.with_extension("answers");
+		let params = path.with_extension("params");
+		let empty = fs::metadata(&path)
+			.unwrap_or_else(|error| panic!("could not read {}: {error}", path.display()))
+			.len()
+			== 0;
+
+		year.examples.push(Example {
+			day,
+			name: name.to_string(),
+			answers: answers.is_file().then_some(answers),
+			params: params.is_file().then_some(params),
+			input: path,
+			empty,
+		});
+	}
+
+	year
+		.examples
+		.sort_unstable_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+}
+
+fn exports_solver(source: &str) -> bool {
+	source.lines().any(|line| {
+		let line = line.trim_start();
+		let line = line.strip_prefix("crate::").unwrap_or(line);
+
+		line.starts_with("export_solver!(solver,") || line.starts_with("generate_solver!(solver,")
+	})
+}
+
+fn generate(years: &[YearDir]) -> String {
+	let mut code = String::from("// Generated by build.rs from the year directories it found.\n");
+
+	for year in years {
+		writeln!(code, "\n#[path = {:?}]", year.dir.display().to_string()).unwrap();
+		writeln!(code, "mod d{} {{", year.year).unwrap();
+		for shared in &year.shared {
+			writeln!(code, "\tmod {shared};").unwrap();
+		}
+		for (_, module) in &year.days {
+			writeln!(code, "\tpub(crate) mod {module};").unwrap();
+		}
+		writeln!(code, "}}").unwrap();
+	}
+
+	writeln!(code, "\n// Every registered solver, in (year, day) order.").unwrap();
+	writeln!(code, "const SOLVERS: &[(u16, u8, MakeSolver)] = &[").unwrap();
+	for year in years {
+		for (day, module) in &year.days {
+			writeln!(code, "\t({0}, {day}, d{0}::{module}::solver),", year.year).unwrap();
+		}
+	}
+	writeln!(code, "];").unwrap();
+
+	writeln!(
+		code,
+		"\n// Days with a module which doesn't export a solver, in (year, day) order."
+	)
+	.unwrap();
+	writeln!(code, "const UNREGISTERED_DAYS: &[(u16, u8)] = &[").unwrap();
+	for year in years {
+		for day in &year.unregistered {
+			writeln!(code, "\t({}, {day}),", year.year).unwrap();
+		}
+	}
+	writeln!(code, "];").unwrap();
+
+	writeln!(
+		code,
+		"\n// Registered days with non-empty example inputs, and how many, in (year, day) order."
+	)
+	.unwrap();
+	writeln!(code, "const EXAMPLE_COUNTS: &[(u16, u8, usize)] = &[").unwrap();
+	for year in years {
+		for (day, _) in &year.days {
+			let count = year
+				.examples
+				.iter()
+				.filter(|example| example.day == *day && !example.empty)
+				.count();
+			if count > 0 {
+				writeln!(code, "\t({}, {day}, {count}),", year.year).unwrap();
+			}
+		}
+	}
+	writeln!(code, "];").unwrap();
+
+	code
+}
+
+fn generate_examples(years: &[YearDir]) -> String {
+	let mut code = String::from(
+		"// Generated by build.rs: a test for every example input, checked against its recorded answers.\n",
+	);
+
+	for year in years.iter().filter(|year| !year.examples.is_empty()) {
+		writeln!(code, "\nmod y{} {{", year.year).unwrap();
+		for example in &year.examples {
+			let name: String = example
+				.name
+				.chars()
+				.map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+				.collect();
+			let included = |path: Option<&PathBuf>| {
+				path.map_or("None".to_string(), |path| {
+					format!("Some(include_str!({:?}))", path.display().to_string())
+				})
+			};
+			let answers = included(example.answers.as_ref());
+			let params = included(example.params.as_ref());
+
+			writeln!(code, "\t#[test]").unwrap();
+			if example.empty {
+				writeln!(code, "\t#[ignore = \"the example is empty\"]").unwrap();
+			}
+			writeln!(code, "\tfn day{:02}_{name}() {{", example.day).unwrap();
+			writeln!(
+				code,
+				"\t\tsuper::check({}, {}, include_str!({:?}), {answers}, {params});",
+				year.year,
+				example.day,
+				example.input.display().to_string()
+			)
+			.unwrap();
+			writeln!(code, "\t}}").unwrap();
+		}
+		writeln!(code, "}}").unwrap();
+	}
+
+	code
+}
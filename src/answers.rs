@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+
+use crate::solver::Part;
+
+/// Known-good answers for a single puzzle, recorded alongside its input file.
+///
+/// The file lives next to the input with an `.answers` extension (e.g. `inputs/2024/day05.answers` for
+/// `inputs/2024/day05.txt`) and contains one `part: answer` line per recorded part:
+///
+/// ```text
+/// part_one: 143
+/// part_two: 123
+/// ```
+///
+/// Blank lines and lines starting with `#` are ignored. Parts without a line are considered unrecorded.
+#[derive(Debug, Default, PartialEq)]
+pub struct RecordedAnswers {
+	part_one: Option<String>,
+	part_two: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnswersError {
+	#[error("I/O error occurred while reading answers file: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("malformed line {line_number} in answers file: {line:?}")]
+	MalformedLine { line_number: usize, line: String },
+	#[error("unknown part {part:?} on line {line_number} of answers file")]
+	UnknownPart { line_number: usize, part: String },
+}
+
+/// The result of comparing a computed answer against a recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+	/// The computed answer matches the recorded answer.
+	Pass,
+	/// The computed answer differs from the recorded answer (or no answer was computed at all).
+	Fail { expected: String },
+	/// No answer has been recorded for this part yet.
+	New,
+}
+
+impl core::fmt::Display for Verdict {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Pass => write!(f, "PASS"),
+			Self::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+			Self::New => write!(f, "NEW"),
+		}
+	}
+}
+
+impl RecordedAnswers {
+	/// Determine where the answers for a given input file are recorded.
+	pub fn path_for(input_file_path: &Path) -> PathBuf {
+		input_file_path.with_extension("answers")
+	}
+
+	/// Load the answers recorded at `path`. A missing file means nothing has been recorded yet.
+	pub fn load(path: &Path) -> Result<Self, AnswersError> {
+		match std::fs::read_to_string(path) {
+			Ok(contents) => contents.parse(),
+			Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+			Err(error) => Err(error.into()),
+		}
+	}
+
+	/// Returns true if no answers have been recorded at all.
+	pub fn is_empty(&self) -> bool {
+		self.part_one.is_none() && self.part_two.is_none()
+	}
+
+	pub fn get(&self, part: Part) -> Option<&str> {
+		match part {
+			Part::One => self.part_one.as_deref(),
+			Part::Two => self.part_two.as_deref(),
+		}
+	}
+
+	/// Compare a computed answer for `part` against the recorded one.
+	///
+	/// Returns `None` if there is nothing to compare: no answer was computed and none was recorded.
+	pub fn verify(&self, part: Part, computed: Option<&str>) -> Option<Verdict> {
+		match (self.get(part), computed) {
+			(None, None) => None,
+			(None, Some(_)) => Some(Verdict::New),
+			(Some(expected), Some(computed)) if expected == computed => Some(Verdict::Pass),
+			(Some(expected), _) => Some(Verdict::Fail {
+				expected: expected.to_string(),
+			}),
+		}
+	}
+}
+
+impl core::str::FromStr for RecordedAnswers {
+	type Err = AnswersError;
+
+	fn from_str(contents: &str) -> Result<Self, Self::Err> {
+		let mut answers = Self::default();
+
+		for (idx, line) in contents.lines().enumerate() {
+			let line_number = idx + 1;
+			let line = line.trim();
+
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let Some((part, answer)) = line.split_once(':') else {
+				return Err(AnswersError::MalformedLine {
+					line_number,
+					line: line.to_string(),
+				});
+			};
+
+			let slot = match part.trim() {
+				"part_one" => &mut answers.part_one,
+				"part_two" => &mut answers.part_two,
+				part => {
+					return Err(AnswersError::UnknownPart {
+						line_number,
+						part: part.to_string(),
+					});
+				}
+			};
+
+			*slot = Some(answer.trim().to_string());
+		}
+
+		Ok(answers)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{AnswersError, Part, RecordedAnswers, Verdict};
+
+	#[test]
+	fn parse_both_parts() {
+		let answers: RecordedAnswers = "part_one: 143\npart_two: 123\n".parse().unwrap();
+
+		assert_eq!(Some("143"), answers.get(Part::One));
+		assert_eq!(Some("123"), answers.get(Part::Two));
+	}
+
+	#[test]
+	fn parse_skips_comments_and_blanks() {
+		let answers: RecordedAnswers = "# 2024 day 5\n\npart_two: abc-def\n".parse().unwrap();
+
+		assert_eq!(None, answers.get(Part::One));
+		assert_eq!(Some("abc-def"), answers.get(Part::Two));
+	}
+
+	#[test]
+	fn parse_rejects_unknown_part() {
+		assert!(matches!(
+			"part_three: 1".parse::<RecordedAnswers>(),
+			Err(AnswersError::UnknownPart { line_number: 1, .. })
+		));
+	}
+
+	#[test]
+	fn verify_verdicts() {
+		let answers: RecordedAnswers = "part_one: 143".parse().unwrap();
+
+		assert_eq!(Some(Verdict::Pass), answers.verify(Part::One, Some("143")));
+		assert_eq!(
+			Some(Verdict::Fail {
+				expected: "143".to_string()
+			}),
+			answers.verify(Part::One, Some("144"))
+		);
+		assert_eq!(
+			Some(Verdict::Fail {
+				expected: "143".to_string()
+			}),
+			answers.verify(Part::One, None)
+		);
+		assert_eq!(Some(Verdict::New), answers.verify(Part::Two, Some("123")));
+		assert_eq!(None, answers.verify(Part::Two, None));
+	}
+}
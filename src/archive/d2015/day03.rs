@@ -131,4 +131,4 @@ pub fn part_two(directions: &Intermediate) -> Option<Output> {
 	Some(map.keys().len())
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
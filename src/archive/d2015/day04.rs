@@ -1,7 +1,18 @@
-pub type Intermediate = String;
-pub type Output = usize;
+use crate::{
+	Solver, TypedPartSolve, export_solver,
+	params::{self, Param, ParamError},
+};
 
-const RANGE: core::ops::Range<usize> = 1_usize..10_000_000_usize;
+struct Solution {
+	/// The number at which the search for a hash gives up.
+	limit: usize,
+}
+
+impl Default for Solution {
+	fn default() -> Self {
+		Self { limit: 10_000_000 }
+	}
+}
 
 const fn is_zero(byte: u8) -> bool {
 	byte == 0_u8
@@ -19,25 +30,57 @@ const fn leading6(data: [u8; 16]) -> bool {
 	is_zero(data[0]) && is_zero(data[1]) && is_zero(data[2])
 }
 
-/// # Errors
-pub fn parse(input: &str) -> anyhow::Result<Intermediate> {
-	Ok(input.lines().map(str::to_string).collect())
+impl Solution {
+	/// Find the lowest positive number below the limit which, after `stub`, hashes to a digest satisfying `leading`.
+	fn mine(&self, stub: &str, leading: fn([u8; 16]) -> bool) -> Option<usize> {
+		(1..self.limit)
+			.map(|n| (n, md5::compute(format!("{stub}{n}"))))
+			.find(|n| leading((n.1).0))
+			.map(|tuple| tuple.0)
+	}
 }
 
-#[must_use]
-pub fn part_one(stub: &Intermediate) -> Option<Output> {
-	RANGE
-		.map(|n| (n, md5::compute(format!("{stub}{n}"))))
-		.find(|n| leading5((n.1).0))
-		.map(|tuple| tuple.0)
-}
+impl TypedPartSolve for Solution {
+	type Intermediate = String;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn params(&self) -> Vec<Param> {
+		vec![Param::new(
+			"limit",
+			&self.limit,
+			"the number at which the search gives up",
+		)]
+	}
+
+	fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+		match name {
+			"limit" => self.limit = params::parse_value(name, value)?,
+			_ => return Err(ParamError::unknown(name, &self.params())),
+		}
 
-#[must_use]
-pub fn part_two(stub: &Intermediate) -> Option<Output> {
-	RANGE
-		.map(|n| (n, md5::compute(format!("{stub}{n}"))))
-		.find(|n| leading6((n.1).0))
-		.map(|tuple| tuple.0)
+		Ok(())
+	}
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+		Ok(input.lines().collect())
+	}
+
+	fn part_one(&self, stub: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		Ok(self.mine(stub, leading5))
+	}
+
+	fn part_two(&self, stub: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		Ok(self.mine(stub, leading6))
+	}
 }
 
-crate::generate_solver!(solve, =>, self);
+export_solver!(solver, Solver::PartSolve(Box::new(Solution::default())));
+
+#[test]
+fn gives_up_at_limit() {
+	let mut solver = Solution::default();
+	crate::PartSolve::set_param(&mut solver, "limit", "1000").unwrap();
+
+	assert_eq!(None, solver.part_one(&"abcdef".to_string()).unwrap());
+}
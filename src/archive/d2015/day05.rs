@@ -1,9 +1,9 @@
-pub type Intermediate<'input> = Vec<&'input str>;
+pub type Intermediate = Vec<String>;
 pub type Output = usize;
 
 /// # Errors
-pub fn parse(input: &str) -> anyhow::Result<Intermediate<'_>> {
-	Ok(input.lines().collect())
+pub fn parse(input: &str) -> anyhow::Result<Intermediate> {
+	Ok(input.lines().map(str::to_string).collect())
 }
 
 #[must_use]
@@ -124,4 +124,4 @@ mod is_nice_new {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
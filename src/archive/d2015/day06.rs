@@ -272,4 +272,4 @@ impl Grid {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -24,22 +24,22 @@ pub fn part_one(connections: &Intermediate) -> Option<Output> {
 
 #[must_use]
 pub fn part_two(connections: &Intermediate) -> Option<Output> {
-	let mut connections: VecDeque<Connection> = connections.clone();
-
-	let mut signal_tracker = process_connections(&connections);
+	part_two_given(connections, &part_one(connections)?)
+}
 
-	let result = signal_tracker.remove(&WireId::from("a")).unwrap();
+/// Solve part two from part one's signal on wire `a`, which overrides wire `b`.
+#[must_use]
+pub fn part_two_given(connections: &Intermediate, signal_a: &Output) -> Option<Output> {
+	let mut connections: VecDeque<Connection> = connections.clone();
 
 	connections.push_back(Connection {
-		input: Input::Source(Source::Signal(result)),
+		input: Input::Source(Source::Signal(*signal_a)),
 		output: WireId("b".to_string()),
 	});
 
 	let mut signal_tracker = process_connections(&connections);
 
-	let result: Option<u16> = signal_tracker.remove(&WireId::from("a"));
-
-	result
+	signal_tracker.remove(&WireId::from("a"))
 }
 
 type Signal = u16;
@@ -447,4 +447,4 @@ mod connection {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self, chained);
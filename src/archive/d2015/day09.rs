@@ -132,4 +132,4 @@ fn total_distance<'processing>(
 		.sum()
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -106,4 +106,4 @@ mod look_and_say {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -1,29 +0,0 @@
-use crate::Solver;
-
-crate::gen_gather_matching_solvers!(2015,
-	day01 (1) => Solver::Original(day01::solve),
-	day02 (2) => Solver::Original(day02::solve),
-	day03 (3) => Solver::Original(day03::solve),
-	day04 (4) => Solver::Original(day04::solve),
-	day05 (5) => Solver::Original(day05::solve),
-	day06 (6) => Solver::Original(day06::solve),
-	day07 (7) => Solver::Original(day07::solve),
-	// day08 (8) => day08::solver(),
-	day09 (9) => Solver::Original(day09::solve),
-	day10 (10) => Solver::Original(day10::solve),
-	// day11 (11) => day11::solver(),
-	// day12 (12) => day12::solver(),
-	// day13 (13) => day13::solver(),
-	// day14 (14) => day14::solver(),
-	// day15 (15) => day15::solver(),
-	// day16 (16) => day16::solver(),
-	// day17 (17) => day17::solver(),
-	// day18 (18) => day18::solver(),
-	// day19 (19) => day19::solver(),
-	// day20 (20) => day20::solver(),
-	// day21 (21) => day21::solver(),
-	// day22 (22) => day22::solver(),
-	// day23 (23) => day23::solver(),
-	// day24 (24) => day24::solver(),
-	// day25 (25) => day25::solver(),
-);
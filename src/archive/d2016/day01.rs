@@ -56,7 +56,7 @@ pub fn part_two(moves: &Intermediate) -> Option<Output> {
 	None
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
 
 use std::collections::BTreeMap;
 
@@ -228,4 +228,4 @@ fn apply_map_to_number(
 	*map.get(&key).expect("expected a key")
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -1,29 +0,0 @@
-use crate::Solver;
-
-crate::gen_gather_matching_solvers!(2016,
-	day01 (1) => Solver::Original(day01::solve),
-	day02 (2) => Solver::Original(day02::solve),
-	// day03 (3) => day03::solver(),
-	// day04 (4) => day04::solver(),
-	// day05 (5) => day05::solver(),
-	// day06 (6) => day06::solver(),
-	// day07 (7) => day07::solver(),
-	// day08 (8) => day08::solver(),
-	// day09 (9) => day09::solver(),
-	// day10 (10) => day10::solver(),
-	// day11 (11) => day11::solver(),
-	// day12 (12) => day12::solver(),
-	// day13 (13) => day13::solver(),
-	// day14 (14) => day14::solver(),
-	// day15 (15) => day15::solver(),
-	// day16 (16) => day16::solver(),
-	// day17 (17) => day17::solver(),
-	// day18 (18) => day18::solver(),
-	// day19 (19) => day19::solver(),
-	// day20 (20) => day20::solver(),
-	// day21 (21) => day21::solver(),
-	// day22 (22) => day22::solver(),
-	// day23 (23) => day23::solver(),
-	// day24 (24) => day24::solver(),
-	// day25 (25) => day25::solver(),
-);
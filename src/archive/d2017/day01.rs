@@ -52,4 +52,4 @@ crate::test_example!(part_two_123425, parse, part_two, "123425", Some(4));
 crate::test_example!(part_two_123123, parse, part_two, "123123", Some(12));
 crate::test_example!(part_two_12131415, parse, part_two, "12131415", Some(4));
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
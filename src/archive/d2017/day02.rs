@@ -61,4 +61,4 @@ crate::test_example!(
 	Some(9)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
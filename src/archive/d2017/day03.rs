@@ -1,5 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use crate::util::neighbors::{self, MOORE_2D};
+
 pub type Intermediate = usize;
 pub type Output = usize;
 
@@ -81,10 +83,8 @@ fn gen_grid_iter() {
 	assert_eq!(gen_grid.next(), Some((0, -1)));
 }
 
-fn neighbors(pos: &(i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
-	(-1..=1)
-		.flat_map(move |offset_y| (-1..=1).map(move |offset_x| (pos.0 + offset_x, pos.1 + offset_y)))
-		.filter(move |pos_t| pos_t != pos)
+fn neighbors(pos: &(i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+	neighbors::around([pos.1, pos.0], &MOORE_2D).map(|[y, x]| (x, y))
 }
 
 #[test]
@@ -123,4 +123,4 @@ fn grid_values_iter() {
 	assert_eq!(grid_values.next(), Some(5));
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
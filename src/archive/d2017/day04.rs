@@ -1,11 +1,11 @@
 use std::collections::BTreeSet;
 
-pub type Intermediate<'input> = Vec<&'input str>;
+pub type Intermediate = Vec<String>;
 pub type Output = usize;
 
 /// # Errors
-pub fn parse(input: &str) -> anyhow::Result<Intermediate<'_>> {
-	let passwords = input.lines().collect();
+pub fn parse(input: &str) -> anyhow::Result<Intermediate> {
+	let passwords = input.lines().map(str::to_string).collect();
 	Ok(passwords)
 }
 
@@ -118,4 +118,4 @@ fn part_two_policy_correct() {
 	);
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -85,4 +85,4 @@ crate::test_example!(
 	Some(10)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
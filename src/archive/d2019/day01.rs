@@ -70,4 +70,4 @@ crate::generate_example_tests!(
 	fuel_part_two_100756 | "100756" => Some(50346)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
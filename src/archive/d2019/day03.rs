@@ -238,4 +238,4 @@ mod tests {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
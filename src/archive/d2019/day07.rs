@@ -60,8 +60,6 @@ pub fn part_one(program: &Intermediate) -> Option<Output> {
 		let mut e = e.run();
 		let output = e.output().expect("expected output from E");
 
-		println!("Setting {permutation:?} produces output {output}");
-
 		if let Some(max_so_far) = max {
 			if output > max_so_far {
 				max.replace(output);
@@ -176,4 +174,4 @@ crate::test_example!(
 	Some(18_216)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
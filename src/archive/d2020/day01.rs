@@ -43,4 +43,4 @@ pub fn part_two(list: &Intermediate) -> Option<Output> {
 #[cfg(test)]
 mod tests;
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
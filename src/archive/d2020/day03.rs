@@ -48,4 +48,4 @@ pub fn part_two(map: &Intermediate) -> Option<Output> {
 #[cfg(test)]
 mod tests;
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
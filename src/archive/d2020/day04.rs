@@ -1,7 +1,7 @@
 use core::hash::BuildHasher;
 use std::collections::HashMap;
 
-pub fn has_required_fields<S: BuildHasher>(passport: &HashMap<&str, &str, S>) -> bool {
+pub fn has_required_fields<S: BuildHasher>(passport: &HashMap<String, String, S>) -> bool {
 	matches!(
 		(
 			passport.get("byr"),
@@ -83,10 +83,10 @@ pub fn valid_hair_color(s: &str) -> bool {
 	}
 }
 
-pub type Intermediate<'a> = Vec<HashMap<&'a str, &'a str>>;
+pub type Intermediate = Vec<HashMap<String, String>>;
 pub type Output = usize;
 
-pub fn parse(data: &str) -> Result<Intermediate<'_>, core::convert::Infallible> {
+pub fn parse(data: &str) -> Result<Intermediate, core::convert::Infallible> {
 	// To start with, passports are separated by \n\n
 	let passports: Vec<&str> = data.split("\n\n").collect();
 
@@ -97,14 +97,14 @@ pub fn parse(data: &str) -> Result<Intermediate<'_>, core::convert::Infallible>
 		.collect();
 
 	// Now, turn those collections of key:value groups into hashmaps
-	let passports: Vec<HashMap<&str, &str>> = passports
+	let passports: Vec<HashMap<String, String>> = passports
 		.iter()
 		.map(|pairs| {
 			pairs
 				.iter()
 				.map(|pair| {
 					let results: Vec<&str> = pair.split(':').collect();
-					(results[0], results[1])
+					(results[0].to_string(), results[1].to_string())
 				})
 				.collect()
 		})
@@ -166,7 +166,7 @@ pub fn part_two(passports: &Intermediate) -> Option<Output> {
 						let expiry_year_ok: bool = valid_expiry_year(expiry_year);
 						let height_ok: bool = valid_height(height);
 						let hair_color_ok: bool = valid_hair_color(hair_color);
-						let eye_color_ok: bool = valid_eye_colors.contains(eye_color);
+						let eye_color_ok: bool = valid_eye_colors.contains(eye_color.as_str());
 						let pid_ok: bool = { pid.len() == 9 && pid.chars().all(|c| c.is_ascii_digit()) };
 
 						birth_year_ok
@@ -187,4 +187,4 @@ pub fn part_two(passports: &Intermediate) -> Option<Output> {
 #[cfg(test)]
 mod tests;
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -49,4 +49,4 @@ pub fn part_two(seat_ids: &Intermediate) -> Option<Output> {
 #[cfg(test)]
 mod tests;
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -23,11 +23,11 @@ pub fn intersect_all(items: impl Iterator<Item = BTreeSet<Answer>>) -> Option<BT
 	)
 }
 
-pub type Intermediate<'a> = Vec<&'a str>;
+pub type Intermediate = Vec<String>;
 pub type Output = usize;
 
-pub fn parse(data: &str) -> Result<Intermediate<'_>, core::convert::Infallible> {
-	Ok(data.split("\n\n").collect())
+pub fn parse(data: &str) -> Result<Intermediate, core::convert::Infallible> {
+	Ok(data.split("\n\n").map(str::to_string).collect())
 }
 
 pub fn part_one(groups: &Intermediate) -> Option<Output> {
@@ -57,4 +57,4 @@ pub fn part_two(groups: &Intermediate) -> Option<Output> {
 #[cfg(test)]
 mod tests;
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
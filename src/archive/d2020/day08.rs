@@ -114,4 +114,4 @@ pub fn part_two(instructions: &Intermediate) -> Option<Output> {
 	accumulator
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -80,4 +80,4 @@ pub fn part_two(numbers: &Intermediate) -> Option<Output> {
 	result
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -124,4 +124,4 @@ fn jolt_differences(adapters: Vec<u64>) -> Vec<u64> {
 #[cfg(test)]
 mod tests {}
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -274,4 +274,4 @@ fn layout_parse() {
 	);
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -237,4 +237,4 @@ fn part_two_example() {
 	assert_eq!(part_two(&instructions), Some(286.0_f64));
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -220,4 +220,4 @@ impl core::str::FromStr for Program {
 #[cfg(test)]
 mod tests {}
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -1,52 +1,79 @@
-const END: u32 = 30_000_000;
-
-pub type Intermediate = Vec<u32>;
-type Output = u32;
-
-pub fn parse(input: &str) -> Result<Intermediate, core::convert::Infallible> {
-	Ok(
-		input
-			.trim()
-			.split(',')
-			.map(|i| i.parse().expect("invalid input"))
-			.collect(),
-	)
+use crate::{
+	Solver, TypedPartSolve, export_solver,
+	params::{self, Param, ParamError},
+};
+
+struct Solution {
+	/// The turn whose number part two asks for.
+	end: u32,
+}
+
+impl Default for Solution {
+	fn default() -> Self {
+		Self { end: 30_000_000 }
+	}
 }
 
-#[allow(clippy::ptr_arg)]
-pub fn part_one(intermediate: &Intermediate) -> Option<Output> {
-	let mut history = vec![0u32; END as usize];
-	let mut last = intermediate[0];
-	for turn in 0..u32::try_from(intermediate.len()).unwrap() {
+/// Play the memory game from the `starting` numbers, returning the number spoken on turn `end`.
+fn play(starting: &[u32], end: u32) -> u32 {
+	let len = u32::try_from(starting.len()).unwrap();
+	let highest = starting.iter().copied().max().unwrap_or_default();
+
+	let mut history = vec![0u32; end.max(highest + 1) as usize];
+	let mut last = starting[0];
+	for turn in 0..len {
 		history[last as usize] = turn;
-		last = intermediate[turn as usize];
+		last = starting[turn as usize];
 	}
 
-	for turn in u32::try_from(intermediate.len()).unwrap()..2020 {
+	for turn in len..end {
 		let stored = history[last as usize];
 		history[last as usize] = turn;
 		last = if stored == 0 { 0 } else { turn - stored };
 	}
 
-	Some(last)
+	last
 }
 
-#[allow(clippy::ptr_arg)]
-pub fn part_two(intermediate: &Intermediate) -> Option<Output> {
-	let mut history = vec![0u32; END as usize];
-	let mut last = intermediate[0];
-	for turn in 0..u32::try_from(intermediate.len()).unwrap() {
-		history[last as usize] = turn;
-		last = intermediate[turn as usize];
+impl TypedPartSolve for Solution {
+	type Intermediate = Vec<u32>;
+	type PartOne = u32;
+	type PartTwo = u32;
+
+	fn params(&self) -> Vec<Param> {
+		vec![Param::new(
+			"end",
+			&self.end,
+			"the turn whose number part two asks for",
+		)]
 	}
 
-	for turn in u32::try_from(intermediate.len()).unwrap()..END {
-		let stored = history[last as usize];
-		history[last as usize] = turn;
-		last = if stored == 0 { 0 } else { turn - stored };
+	fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+		match name {
+			"end" => self.end = params::parse_value(name, value)?,
+			_ => return Err(ParamError::unknown(name, &self.params())),
+		}
+
+		Ok(())
+	}
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+		Ok(
+			input
+				.trim()
+				.split(',')
+				.map(str::parse)
+				.collect::<Result<_, _>>()?,
+		)
+	}
+
+	fn part_one(&self, starting: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		Ok(Some(play(starting, 2020)))
 	}
 
-	Some(last)
+	fn part_two(&self, starting: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		Ok(Some(play(starting, self.end)))
+	}
 }
 
-crate::generate_solver!(solve, =>, self);
+export_solver!(solver, Solver::PartSolve(Box::new(Solution::default())));
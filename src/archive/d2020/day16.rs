@@ -267,4 +267,4 @@ nearby tickets:
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::util::neighbors::{self, MOORE_3D, MOORE_4D};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Pos(i8, i8, i8, i8);
 
@@ -47,62 +49,24 @@ mod parse {
 	}
 }
 
-#[rustfmt::skip]
-static NEIGHBOR_D: [(i8, i8, i8, i8); 80] = [
-	// dw=-1
-	(-1, -1, -1, -1), (-1, -1,  0, -1), (-1, -1,  1, -1),
-	(-1,  0, -1, -1), (-1,  0,  0, -1), (-1,  0,  1, -1),
-	(-1,  1, -1, -1), (-1,  1,  0, -1), (-1,  1,  1, -1),
-	( 0, -1, -1, -1), ( 0, -1,  0, -1), ( 0, -1,  1, -1),
-	( 0,  0, -1, -1), ( 0,  0,  0, -1), ( 0,  0,  1, -1),
-	( 0,  1, -1, -1), ( 0,  1,  0, -1), ( 0,  1,  1, -1),
-	( 1, -1, -1, -1), ( 1, -1,  0, -1), ( 1, -1,  1, -1),
-	( 1,  0, -1, -1), ( 1,  0,  0, -1), ( 1,  0,  1, -1),
-	( 1,  1, -1, -1), ( 1,  1,  0, -1), ( 1,  1,  1, -1),
-
-	// dw=0
-	(-1, -1, -1,  0), (-1, -1,  0,  0), (-1, -1,  1,  0),
-	(-1,  0, -1,  0), (-1,  0,  0,  0), (-1,  0,  1,  0),
-	(-1,  1, -1,  0), (-1,  1,  0,  0), (-1,  1,  1,  0),
-	( 0, -1, -1,  0), ( 0, -1,  0,  0), ( 0, -1,  1,  0),
-	( 0,  0, -1,  0),                   ( 0,  0,  1,  0),
-	( 0,  1, -1,  0), ( 0,  1,  0,  0), ( 0,  1,  1,  0),
-	( 1, -1, -1,  0), ( 1, -1,  0,  0), ( 1, -1,  1,  0),
-	( 1,  0, -1,  0), ( 1,  0,  0,  0), ( 1,  0,  1,  0),
-	( 1,  1, -1,  0), ( 1,  1,  0,  0), ( 1,  1,  1,  0),
-
-	// dw=+1
-	(-1, -1, -1,  1), (-1, -1,  0,  1), (-1, -1,  1,  1),
-	(-1,  0, -1,  1), (-1,  0,  0,  1), (-1,  0,  1,  1),
-	(-1,  1, -1,  1), (-1,  1,  0,  1), (-1,  1,  1,  1),
-	( 0, -1, -1,  1), ( 0, -1,  0,  1), ( 0, -1,  1,  1),
-	( 0,  0, -1,  1), ( 0,  0,  0,  1), ( 0,  0,  1,  1),
-	( 0,  1, -1,  1), ( 0,  1,  0,  1), ( 0,  1,  1,  1),
-	( 1, -1, -1,  1), ( 1, -1,  0,  1), ( 1, -1,  1,  1),
-	( 1,  0, -1,  1), ( 1,  0,  0,  1), ( 1,  0,  1,  1),
-	( 1,  1, -1,  1), ( 1,  1,  0,  1), ( 1,  1,  1,  1),
-];
-
 fn count_neighbors_3d(active: &HashSet<Pos>) -> HashMap<Pos, usize> {
-	let mut neighbors = HashMap::new();
+	let mut counts = HashMap::new();
 	for Pos(x, y, z, _) in active {
-		for (dx, dy, dz, _dw) in &NEIGHBOR_D[26..52] {
-			*neighbors.entry(Pos(x + dx, y + dy, z + dz, 0)).or_insert(0) += 1;
+		for [x, y, z] in neighbors::around([*x, *y, *z], &MOORE_3D) {
+			*counts.entry(Pos(x, y, z, 0)).or_insert(0) += 1;
 		}
 	}
-	neighbors
+	counts
 }
 
 fn count_neighbors_4d(active: &HashSet<Pos>) -> HashMap<Pos, usize> {
-	let mut neighbors = HashMap::new();
+	let mut counts = HashMap::new();
 	for Pos(x, y, z, w) in active {
-		for (dx, dy, dz, dw) in &NEIGHBOR_D {
-			*neighbors
-				.entry(Pos(x + dx, y + dy, z + dz, w + dw))
-				.or_insert(0) += 1;
+		for [x, y, z, w] in neighbors::around([*x, *y, *z, *w], &MOORE_4D) {
+			*counts.entry(Pos(x, y, z, w)).or_insert(0) += 1;
 		}
 	}
-	neighbors
+	counts
 }
 
 fn process<CountingFn>(mut active: HashSet<Pos>, counting_fn: CountingFn) -> usize
@@ -160,4 +124,4 @@ mod part_two {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -167,15 +167,18 @@ impl From<&RuleSet> for Regex {
 }
 
 #[derive(Debug)]
-pub struct Message<'x>(&'x str);
+pub struct Message(String);
 
-pub type Intermediate<'input> = (RuleSet, Vec<Message<'input>>);
+pub type Intermediate = (RuleSet, Vec<Message>);
 type Output = usize;
 
-pub fn parse(data: &str) -> Result<Intermediate<'_>, core::convert::Infallible> {
+pub fn parse(data: &str) -> Result<Intermediate, core::convert::Infallible> {
 	let split: Vec<&str> = data.split("\n\n").collect();
 
-	let messages: Vec<Message> = split[1].lines().map(Message).collect();
+	let messages: Vec<Message> = split[1]
+		.lines()
+		.map(|line| Message(line.to_string()))
+		.collect();
 
 	Ok((split[0].parse().unwrap(), messages))
 }
@@ -185,7 +188,7 @@ pub fn part_one((rules, messages): &Intermediate) -> Option<Output> {
 
 	let count = messages
 		.iter()
-		.filter(|message| ruleset_regex.is_match(message.0))
+		.filter(|message| ruleset_regex.is_match(&message.0))
 		.count();
 
 	Some(count)
@@ -195,4 +198,4 @@ pub fn part_two(_: &Intermediate) -> Option<Output> {
 	None
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
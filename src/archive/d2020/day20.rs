@@ -9,7 +9,7 @@ const MONSTER: [&str; 3] = [
 ];
 
 type BorderMatches = HashMap<String, Vec<usize>>;
-type Image<'a> = [&'a str; 10];
+type Image = [String; 10];
 
 #[derive(Clone, Debug, Default)]
 struct Tile {
@@ -18,7 +18,7 @@ struct Tile {
 }
 
 impl Tile {
-	fn from_input(tile: &[&str], id: usize) -> Self {
+	fn from_input(tile: &[String], id: usize) -> Self {
 		Self {
 			v: tile.iter().map(|s| s.chars().collect()).collect(),
 			id,
@@ -49,9 +49,9 @@ impl Tile {
 		self.v = rotate(&self.v);
 	}
 
-	fn match_right(&self, matches: &BorderMatches, images: &HashMap<usize, Image>) -> Self {
+	fn match_right(&self, matches: &BorderMatches, images: &HashMap<usize, &Image>) -> Self {
 		let id = self.get_neighbor(matches, 1).unwrap();
-		let mut tile = Tile::from_input(&images[&id], id);
+		let mut tile = Tile::from_input(images[&id], id);
 
 		while tile.get_neighbor(matches, 3) != Some(self.id) {
 			tile.rotate();
@@ -65,9 +65,9 @@ impl Tile {
 		tile
 	}
 
-	fn match_down(&self, matches: &BorderMatches, images: &HashMap<usize, Image>) -> Self {
+	fn match_down(&self, matches: &BorderMatches, images: &HashMap<usize, &Image>) -> Self {
 		let id = self.get_neighbor(matches, 2).unwrap();
-		let mut tile = Tile::from_input(&images[&id], id);
+		let mut tile = Tile::from_input(images[&id], id);
 
 		while tile.get_neighbor(matches, 0) != Some(self.id) {
 			tile.rotate();
@@ -96,9 +96,12 @@ fn build_image(
 	matches: &BorderMatches,
 	corner: usize,
 ) -> Vec<Vec<char>> {
-	let images = images.iter().copied().collect::<HashMap<_, _>>();
+	let images = images
+		.iter()
+		.map(|(id, image)| (*id, image))
+		.collect::<HashMap<_, _>>();
 
-	let mut starting_corner = Tile::from_input(&images[&corner], corner);
+	let mut starting_corner = Tile::from_input(images[&corner], corner);
 	while [0, 3]
 		.iter()
 		.any(|&d| starting_corner.get_neighbor(matches, d).is_some())
@@ -153,7 +156,7 @@ fn find_monsters(image: &[Vec<char>], monster_coords: &HashSet<(isize, isize)>)
 		.count()
 }
 
-pub type Intermediate<'a> = (Vec<(usize, [&'a str; 10])>, BorderMatches);
+pub type Intermediate = (Vec<(usize, Image)>, BorderMatches);
 pub type Output = usize;
 
 pub fn part_one((_images, matches): &Intermediate) -> Option<Output> {
@@ -199,7 +202,7 @@ pub fn part_two((images, matches): &Intermediate) -> Option<Output> {
 	}
 }
 
-pub fn parse(input: &str) -> Result<Intermediate<'_>, core::convert::Infallible> {
+pub fn parse(input: &str) -> Result<Intermediate, core::convert::Infallible> {
 	let images: Vec<(usize, Image)> = input
 		.split("\n\n")
 		.map(|spec| {
@@ -215,7 +218,12 @@ pub fn parse(input: &str) -> Result<Intermediate<'_>, core::convert::Infallible>
 				.parse()
 				.unwrap();
 
-			let body: Image = lines[1..=10].try_into().unwrap();
+			let body: Image = lines[1..=10]
+				.iter()
+				.map(|line| (*line).to_string())
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap();
 
 			(id, body)
 		})
@@ -223,12 +231,12 @@ pub fn parse(input: &str) -> Result<Intermediate<'_>, core::convert::Infallible>
 
 	let mut matches = HashMap::new();
 
-	for &(id, tile) in &images {
-		let (b1, b2) = Tile::from_input(&tile, id).get_edges();
+	for &(id, ref tile) in &images {
+		let (b1, b2) = Tile::from_input(tile, id).get_edges();
 
-		for edge in &[tile[0], tile[9], &b1, &b2] {
+		for edge in [&tile[0], &tile[9], &b1, &b2] {
 			matches
-				.entry((*edge).to_string())
+				.entry(edge.clone())
 				.or_insert_with(Vec::new)
 				.push(id);
 
@@ -242,4 +250,4 @@ pub fn parse(input: &str) -> Result<Intermediate<'_>, core::convert::Infallible>
 	Ok((images, matches))
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -1,3 +1,5 @@
+use crate::util::math;
+
 pub type Intermediate = (usize, usize);
 type Output = usize;
 
@@ -18,27 +20,27 @@ pub fn part_two(_: &Intermediate) -> Option<Output> {
 	None
 }
 
-fn transform_sub(subject: usize, loop_size: usize) -> usize {
-	let mut value: usize = 1_usize;
+const MODULUS: u64 = 20_201_227;
 
-	for _ in 0..loop_size {
-		value *= subject;
-		value %= 20_201_227_usize;
-	}
+fn transform_sub(subject: usize, loop_size: usize) -> usize {
+	let power = math::mod_pow(
+		u64::try_from(subject).unwrap(),
+		u64::try_from(loop_size).unwrap(),
+		MODULUS,
+	);
 
-	value
+	usize::try_from(power).unwrap()
 }
 
 fn find_loop_size_for_key(pubkey: usize, subject: usize) -> usize {
-	let mut value = 1_usize;
-	let mut loop_size = 0;
-
-	while value != pubkey {
-		value = value * subject % 20_201_227;
-		loop_size += 1;
-	}
-
-	loop_size
+	let loop_size = math::discrete_log(
+		u64::try_from(subject).unwrap(),
+		u64::try_from(pubkey).unwrap(),
+		MODULUS,
+	)
+	.unwrap();
+
+	usize::try_from(loop_size).unwrap()
 }
 
 #[test]
@@ -145,4 +147,4 @@ fn find_encryption_key_d17807724_c5764801() {
 	);
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
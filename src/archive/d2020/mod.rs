@@ -1,29 +0,0 @@
-use crate::Solver;
-
-crate::gen_gather_matching_solvers!(2020,
-	day01 (1) => Solver::Original(day01::solve),
-	day02 (2) => Solver::Original(day02::solve),
-	day03 (3) => Solver::Original(day03::solve),
-	day04 (4) => Solver::Original(day04::solve),
-	day05 (5) => Solver::Original(day05::solve),
-	day06 (6) => Solver::Original(day06::solve),
-	// day07 (7) => Solver::Original(day07::solve),
-	day08 (8) => Solver::Original(day08::solve),
-	day09 (9) => Solver::Original(day09::solve),
-	day10 (10) => Solver::Original(day10::solve),
-	day11 (11) => Solver::Original(day11::solve),
-	day12 (12) => Solver::Original(day12::solve),
-	day13 (13) => Solver::Original(day13::solve),
-	day14 (14) => Solver::Original(day14::solve),
-	day15 (15) => Solver::Original(day15::solve),
-	day16 (16) => Solver::Original(day16::solve),
-	day17 (17) => Solver::Original(day17::solve),
-	day18 (18) => Solver::Original(day18::solve),
-	day19 (19) => Solver::Original(day19::solve),
-	day20 (20) => Solver::Original(day20::solve),
-	day21 (21) => Solver::Original(day21::solve),
-	day22 (22) => Solver::Original(day22::solve),
-	// day23 (23) => day23::solver(),
-	// day24 (24) => day24::solver(),
-	day25 (25) => Solver::Original(day25::solve),
-);
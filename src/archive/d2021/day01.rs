@@ -31,4 +31,4 @@ pub fn part_two(readings: &Intermediate) -> Option<Output> {
 	)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -131,4 +131,4 @@ pub fn part_two((calls, boards): &Intermediate) -> Option<Output> {
 	Some(score as usize)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
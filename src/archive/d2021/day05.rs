@@ -223,4 +223,4 @@ pub fn part_two(segments: &Intermediate) -> Option<Output> {
 	Some(overlaps)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
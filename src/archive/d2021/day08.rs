@@ -1,8 +1,8 @@
 use std::collections::HashSet;
 
-pub type Intermediate<'a> = Vec<([&'a str; 10], [&'a str; 4])>;
+pub type Intermediate = Vec<([String; 10], [String; 4])>;
 
-pub fn parse(input: &str) -> Result<Intermediate<'_>, core::convert::Infallible> {
+pub fn parse(input: &str) -> Result<Intermediate, core::convert::Infallible> {
 	Ok(
 		input
 			.lines()
@@ -10,8 +10,18 @@ pub fn parse(input: &str) -> Result<Intermediate<'_>, core::convert::Infallible>
 			.map(|mut split| (split.next().unwrap(), split.next().unwrap()))
 			.map(|(signals, outputs)| {
 				(
-					signals.split(' ').collect::<Vec<_>>().try_into().unwrap(),
-					outputs.split(' ').collect::<Vec<_>>().try_into().unwrap(),
+					signals
+						.split(' ')
+						.map(str::to_string)
+						.collect::<Vec<_>>()
+						.try_into()
+						.unwrap(),
+					outputs
+						.split(' ')
+						.map(str::to_string)
+						.collect::<Vec<_>>()
+						.try_into()
+						.unwrap(),
 				)
 			})
 			.collect(),
@@ -43,7 +53,7 @@ const SEGMENT_BOTTOM_LEFT: usize = 4;
 const SEGMENT_TOP_LEFT: usize = 5;
 const SEGMENT_CENTER: usize = 6;
 
-fn solve_segments(signals: &[&str; 10]) -> [HashSet<char>; 10] {
+fn solve_segments(signals: &[impl AsRef<str>; 10]) -> [HashSet<char>; 10] {
 	let mut possibilities: [HashSet<char>; 7] = [
 		all_possibilities(),
 		all_possibilities(),
@@ -54,7 +64,10 @@ fn solve_segments(signals: &[&str; 10]) -> [HashSet<char>; 10] {
 		all_possibilities(),
 	];
 
-	let signals: Vec<HashSet<char>> = signals.iter().map(|s| s.chars().collect()).collect();
+	let signals: Vec<HashSet<char>> = signals
+		.iter()
+		.map(|s| s.as_ref().chars().collect())
+		.collect();
 
 	// First, we can trivially infer which of the signals corresponds to 1, 4, 7, and 8, because
 	// these each have a unique number of connections.
@@ -221,11 +234,11 @@ fn solve_segments_works() {
 	assert_eq!(signal_digits[5], vec!['b', 'c', 'd', 'e', 'f']);
 }
 
-fn apply_segments(solved_digits: &[HashSet<char>; 10], outputs: &[&str; 4]) -> usize {
+fn apply_segments(solved_digits: &[HashSet<char>; 10], outputs: &[impl AsRef<str>; 4]) -> usize {
 	let digits: Vec<usize> = outputs
 		.iter()
-		.map(|&output| {
-			let output_chars: HashSet<char> = output.chars().collect();
+		.map(|output| {
+			let output_chars: HashSet<char> = output.as_ref().chars().collect();
 			solved_digits
 				.iter()
 				.enumerate()
@@ -248,4 +261,4 @@ pub fn part_two(parts: &Intermediate) -> Option<Output> {
 	)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -155,4 +155,4 @@ fn part_two_examples() {
 	assert_eq!(Some(1134), part_two(&height_map));
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -170,11 +170,7 @@ pub fn part_two(subsystem: &Intermediate) -> Option<Output> {
 
 	let valid_line_scores: Vec<u64> = valid_line_scores.into_iter().collect();
 
-	for (idx, line) in valid_line_scores.iter().enumerate() {
-		println!("{:2} {}", idx + 1, line);
-	}
-
 	Some(valid_line_scores[valid_line_scores.len() / 2])
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
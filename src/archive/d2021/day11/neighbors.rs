@@ -1,18 +1,11 @@
-pub fn neighbors<const N: usize>(y_0: u8, x_0: u8) -> impl Iterator<Item = (u8, u8)> {
-	assert!(u8::try_from(N).is_ok());
+use crate::util::neighbors::{self, MOORE_2D};
 
+pub fn neighbors<const N: usize>(y_0: u8, x_0: u8) -> impl Iterator<Item = (u8, u8)> {
 	let upper_bound = u8::try_from(N).unwrap();
 
-	let y_min = y_0.saturating_sub(1);
-	let y_max = y_0.saturating_add(1).clamp(0, upper_bound - 1);
-
-	let x_min = x_0.saturating_sub(1);
-	let x_max = x_0.saturating_add(1).clamp(0, upper_bound - 1);
-
-	(y_min..=y_max)
-		.flat_map(move |y| (x_min..=x_max).map(move |x| (y, x)))
-		.filter(move |(y, x)| y != &y_0 || x != &x_0)
+	neighbors::bounded([y_0, x_0], &MOORE_2D, [0..upper_bound, 0..upper_bound]).map(|[y, x]| (y, x))
 }
+
 #[cfg(test)]
 mod _5 {
 	use super::neighbors;
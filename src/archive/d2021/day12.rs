@@ -4,21 +4,27 @@ mod node;
 use node::Node;
 
 mod edge;
+use edge::Edge;
 
-mod graph;
-use graph::Graph;
+use crate::util::graph::Graph;
 
-pub type Intermediate = Graph;
+pub type Intermediate = Graph<Node>;
 
 pub fn parse(input: &str) -> Result<Intermediate, core::convert::Infallible> {
-	input.lines().map(FromStr::from_str).collect()
+	let mut graph = Graph::undirected();
+	for edge in input.lines().map(Edge::from_str) {
+		let (left, right) = edge?.into();
+		graph.add_edge(left, right);
+	}
+
+	Ok(graph)
 }
 
 type Output = usize;
 
 #[must_use]
 pub fn part_one(graph: &Intermediate) -> Option<Output> {
-	println!("{}", graph.to_graphviz());
+	eprintln!("{}", graph.to_dot());
 
 	None
 }
@@ -28,4 +34,4 @@ pub fn part_two(_graph: &Intermediate) -> Option<Output> {
 	None
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
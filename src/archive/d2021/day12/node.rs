@@ -4,7 +4,7 @@ use core::{
 	str::FromStr,
 };
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Node {
 	Start,
 	SmallCave(String),
@@ -2,16 +2,14 @@ use std::collections::{BTreeMap, btree_map::Entry};
 
 use itertools::Itertools;
 
-pub type Intermediate<'input> = (&'input str, BTreeMap<&'input str, char>);
+pub type Intermediate = (String, BTreeMap<String, char>);
 
-pub fn parse<'input>(
-	input: &'input str,
-) -> Result<Intermediate<'input>, core::convert::Infallible> {
+pub fn parse(input: &str) -> Result<Intermediate, core::convert::Infallible> {
 	let mut split = input.split("\n\n");
-	let first_line: &'input str = split.next().expect("missing template");
-	let insertion_rules: &'input str = split.next().expect("missing rules");
+	let first_line: &str = split.next().expect("missing template");
+	let insertion_rules: &str = split.next().expect("missing rules");
 
-	let insertion_rules: BTreeMap<&'input str, char> = insertion_rules
+	let insertion_rules: BTreeMap<String, char> = insertion_rules
 		.lines()
 		.map(|line| {
 			let mut parts = line.split(" -> ");
@@ -24,11 +22,11 @@ pub fn parse<'input>(
 				.next()
 				.expect("missing insertion character");
 
-			(pair, element)
+			(pair.to_string(), element)
 		})
 		.collect();
 
-	Ok((first_line, insertion_rules))
+	Ok((first_line.to_string(), insertion_rules))
 }
 
 type Output = usize;
@@ -36,7 +34,7 @@ type Output = usize;
 fn one_step_inner(
 	(_a_idx, a_c): (usize, char),
 	(b_idx, b_c): (usize, char),
-	rules: &BTreeMap<&str, char>,
+	rules: &BTreeMap<String, char>,
 	len: usize,
 ) -> String {
 	let last = b_idx == len - 1;
@@ -58,7 +56,7 @@ fn one_step_inner(
 	pair.iter().collect()
 }
 
-fn one_step(existing_polymer: &str, rules: &BTreeMap<&str, char>) -> String {
+fn one_step(existing_polymer: &str, rules: &BTreeMap<String, char>) -> String {
 	let len = existing_polymer.len();
 	existing_polymer
 		.chars()
@@ -70,22 +68,28 @@ fn one_step(existing_polymer: &str, rules: &BTreeMap<&str, char>) -> String {
 
 #[test]
 fn one_step_example_nncb_nn_c() {
-	let rules = vec![("NN", 'C')].into_iter().collect();
+	let rules = [("NN".to_string(), 'C')].into_iter().collect();
 
 	assert_eq!(one_step("NNCB", &rules), "NCNCB");
 }
 
 #[test]
 fn one_step_example_nncb_nn_c_nc_b() {
-	let rules = vec![("NN", 'C'), ("NC", 'B')].into_iter().collect();
+	let rules = [("NN".to_string(), 'C'), ("NC".to_string(), 'B')]
+		.into_iter()
+		.collect();
 	assert_eq!(one_step("NNCB", &rules), "NCNBCB");
 }
 
 #[test]
 fn one_step_example_nncb_nn_c_nc_b_cb_h() {
-	let rules = vec![("NN", 'C'), ("NC", 'B'), ("CB", 'H')]
-		.into_iter()
-		.collect();
+	let rules = [
+		("NN".to_string(), 'C'),
+		("NC".to_string(), 'B'),
+		("CB".to_string(), 'H'),
+	]
+	.into_iter()
+	.collect();
 
 	assert_eq!(one_step("NNCB", &rules), "NCNBCHB");
 }
@@ -105,11 +109,10 @@ fn statistics(string: &str) -> BTreeMap<char, usize> {
 
 #[must_use]
 pub fn part_one((template, rules): &Intermediate) -> Option<Output> {
-	let mut template = (*template).to_string();
+	let mut template = template.clone();
 
-	for step in 0..10 {
+	for _ in 0..10 {
 		template = one_step(&template, rules);
-		println!("Step {}: length = {}", step + 1, template.len());
 	}
 
 	let statistics = statistics(&template);
@@ -122,11 +125,10 @@ pub fn part_one((template, rules): &Intermediate) -> Option<Output> {
 
 #[must_use]
 pub fn part_two((template, rules): &Intermediate) -> Option<Output> {
-	let mut template = (*template).to_string();
+	let mut template = template.clone();
 
-	for step in 0..40 {
+	for _ in 0..40 {
 		template = one_step(&template, rules);
-		println!("Step {}: length = {}", step + 1, template.len());
 	}
 
 	let statistics = statistics(&template);
@@ -137,4 +139,4 @@ pub fn part_two((template, rules): &Intermediate) -> Option<Output> {
 	Some(qty_max - qty_min)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -246,7 +246,7 @@ pub fn part_one((alu, program): &Intermediate) -> Option<Output> {
 		.inspect(|_n| {
 			counter += 1;
 			if counter.is_multiple_of(1_000_000) {
-				println!(
+				eprintln!(
 					"Processed {} of {} ({}%)",
 					counter,
 					COUNTER_MAX_EST,
@@ -278,4 +278,4 @@ pub fn part_two(_intermediate: &Intermediate) -> Option<Output> {
 	None
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -2,6 +2,11 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
+use crate::{
+	Solver, TypedPartSolve, export_solver,
+	params::{self, Param, ParamError},
+};
+
 #[derive(PartialEq, Clone, Copy)]
 enum Direction {
 	East,
@@ -25,19 +30,21 @@ impl TryFrom<char> for Direction {
 	}
 }
 
-#[derive(Copy, Clone)]
-pub struct Space<const WIDTH: usize, const HEIGHT: usize> {
-	contents: [[Option<Direction>; WIDTH]; HEIGHT],
+#[derive(Clone)]
+pub struct Space {
+	width: usize,
+	height: usize,
+	contents: Vec<Vec<Option<Direction>>>,
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> core::fmt::Display for Space<WIDTH, HEIGHT> {
+impl core::fmt::Display for Space {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		for y in 0..HEIGHT {
-			for x in 0..WIDTH {
+		for row in &self.contents {
+			for cell in row {
 				write!(
 					f,
 					"{}",
-					match self.contents[y][x] {
+					match cell {
 						Some(Direction::East) => '>',
 						Some(Direction::South) => 'v',
 						None => '.',
@@ -50,10 +57,8 @@ impl<const WIDTH: usize, const HEIGHT: usize> core::fmt::Display for Space<WIDTH
 	}
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> core::str::FromStr for Space<WIDTH, HEIGHT> {
-	type Err = core::convert::Infallible;
-
-	fn from_str(str: &str) -> Result<Self, Self::Err> {
+impl Space {
+	fn parse(str: &str, width: usize, height: usize) -> anyhow::Result<Self> {
 		let entries: Vec<((usize, usize), Direction)> = str
 			.lines()
 			.enumerate()
@@ -70,108 +75,132 @@ impl<const WIDTH: usize, const HEIGHT: usize> core::str::FromStr for Space<WIDTH
 			})
 			.collect();
 
-		let mut contents = [[None; WIDTH]; HEIGHT];
+		let mut contents = vec![vec![None; width]; height];
 
 		for ((x, y), direction) in entries {
-			contents[y][x] = Some(direction);
-		}
-
-		Ok(Self { contents })
-	}
-}
-
-impl<const WIDTH: usize, const HEIGHT: usize> Space<WIDTH, HEIGHT> {
-	fn next_east(x: usize) -> usize {
-		let mut x = x;
-
-		x += 1;
-
-		while x >= WIDTH {
-			x -= WIDTH;
-		}
-
-		x
-	}
-
-	fn next_south(y: usize) -> usize {
-		let mut y = y;
-
-		y += 1;
+			if x >= width || y >= height {
+				anyhow::bail!("sea cucumber at ({x}, {y}) is outside the {width}x{height} space");
+			}
 
-		while y >= HEIGHT {
-			y -= HEIGHT;
+			contents[y][x] = Some(direction);
 		}
 
-		y
+		Ok(Self {
+			width,
+			height,
+			contents,
+		})
 	}
 
 	fn iterate(self) -> (Self, usize) {
-		let mut contents = self.contents;
+		let Self {
+			width,
+			height,
+			mut contents,
+		} = self;
+		let next_east = |x: usize| (x + 1) % width;
+		let next_south = |y: usize| (y + 1) % height;
 
 		let mut moves = 0;
 
-		//println!("{}", self);
-
-		let leading_east_edge: Vec<(usize, usize)> = (0..HEIGHT)
-			.flat_map(|y| (0..WIDTH).map(move |x| (WIDTH - 1 - x, y)))
+		let leading_east_edge: Vec<(usize, usize)> = (0..height)
+			.flat_map(|y| (0..width).map(move |x| (width - 1 - x, y)))
 			.filter(|(x, y)| contents[*y][*x] == Some(Direction::East))
-			.filter(|&(x, y)| contents[y][Self::next_east(x)].is_none())
+			.filter(|&(x, y)| contents[y][next_east(x)].is_none())
 			.collect();
 
 		for (x, y) in leading_east_edge {
-			contents[y][Self::next_east(x)] = contents[y][x];
+			contents[y][next_east(x)] = contents[y][x];
 			contents[y][x] = None;
 			moves += 1;
 		}
 
-		let leading_south_edge: Vec<(usize, usize)> = (0..WIDTH)
-			.flat_map(|x| (0..HEIGHT).map(move |y| (x, HEIGHT - 1 - y)))
+		let leading_south_edge: Vec<(usize, usize)> = (0..width)
+			.flat_map(|x| (0..height).map(move |y| (x, height - 1 - y)))
 			.filter(|(x, y)| contents[*y][*x] == Some(Direction::South))
-			.filter(|&(x, y)| contents[Self::next_south(y)][x].is_none())
+			.filter(|&(x, y)| contents[next_south(y)][x].is_none())
 			.collect();
 
 		for (x, y) in leading_south_edge {
-			contents[Self::next_south(y)][x] = contents[y][x];
+			contents[next_south(y)][x] = contents[y][x];
 			contents[y][x] = None;
 			moves += 1;
 		}
 
-		(Self { contents }, moves)
+		(
+			Self {
+				width,
+				height,
+				contents,
+			},
+			moves,
+		)
 	}
 }
 
-pub type Intermediate = Space<139, 137>;
+struct Solution {
+	width: usize,
+	height: usize,
+}
 
-pub fn parse(input: &str) -> Result<Intermediate, core::convert::Infallible> {
-	Ok(input.parse().expect("failed to parse input"))
+impl Default for Solution {
+	fn default() -> Self {
+		Self {
+			width: 139,
+			height: 137,
+		}
+	}
 }
 
-type Output = usize;
+impl TypedPartSolve for Solution {
+	type Intermediate = Space;
+	type PartOne = usize;
+	type PartTwo = usize;
 
-#[must_use]
-pub fn part_one(floor: &Intermediate) -> Option<Output> {
-	let mut space: Intermediate = (*floor);
+	fn params(&self) -> Vec<Param> {
+		vec![
+			Param::new("width", &self.width, "the width of the sea floor"),
+			Param::new("height", &self.height, "the height of the sea floor"),
+		]
+	}
 
-	let mut counter = 0;
+	fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+		match name {
+			"width" => self.width = params::parse_value(name, value)?,
+			"height" => self.height = params::parse_value(name, value)?,
+			_ => return Err(ParamError::unknown(name, &self.params())),
+		}
 
-	loop {
-		let (new_space, count) = space.iterate();
+		Ok(())
+	}
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+		Space::parse(input, self.width, self.height)
+	}
 
-		counter += 1;
+	fn part_one(&self, floor: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		let mut space = floor.clone();
 
-		if count == 0 {
-			break;
+		let mut counter = 0;
+
+		loop {
+			let (new_space, count) = space.iterate();
+
+			counter += 1;
+
+			if count == 0 {
+				break;
+			}
+
+			space = new_space;
 		}
 
-		space = new_space;
+		Ok(Some(counter))
 	}
 
-	Some(counter)
-}
-
-#[must_use]
-pub fn part_two(_intermediate: &Intermediate) -> Option<Output> {
-	None
+	fn part_two(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		Ok(None)
+	}
 }
 
-crate::generate_solver!(solve, =>, self);
+export_solver!(solver, Solver::PartSolve(Box::new(Solution::default())));
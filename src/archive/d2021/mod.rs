@@ -1,29 +0,0 @@
-use crate::Solver;
-
-crate::gen_gather_matching_solvers!(2021,
-	day01 (1) => Solver::Original(day01::solve),
-	day02 (2) => Solver::Original(day02::solve),
-	day03 (3) => Solver::Original(day03::solve),
-	day04 (4) => Solver::Original(day04::solve),
-	day05 (5) => Solver::Original(day05::solve),
-	day06 (6) => Solver::Original(day06::solve),
-	day07 (7) => Solver::Original(day07::solve),
-	day08 (8) => Solver::Original(day08::solve),
-	day09 (9) => Solver::Original(day09::solve),
-	day10 (10) => Solver::Original(day10::solve),
-	day11 (11) => Solver::Original(day11::solve),
-	day12 (12) => Solver::Original(day12::solve),
-	// day13 (13) => day13::solver(),
-	day14 (14) => Solver::Original(day14::solve),
-	// day15 (15) => day15::solver(),
-	// day16 (16) => day16::solver(),
-	// day17 (17) => day17::solver(),
-	// day18 (18) => day18::solver(),
-	// day19 (19) => day19::solver(),
-	// day20 (20) => day20::solver(),
-	// day21 (21) => day21::solver(),
-	// day22 (22) => day22::solver(),
-	day23 (23) => Solver::Original(day23::solve),
-	day24 (24) => Solver::Original(day24::solve),
-	day25 (25) => Solver::Original(day25::solve),
-);
@@ -31,7 +31,7 @@ impl FromStr for Move {
 	}
 }
 
-pub struct StrategyPart<'p>(&'p str, &'p str);
+pub struct StrategyPart(String, String);
 
 enum Outcome {
 	Win,
@@ -49,7 +49,7 @@ impl Outcome {
 	}
 }
 
-impl StrategyPart<'_> {
+impl StrategyPart {
 	fn score_as_move_move(&self) -> u32 {
 		use {
 			Move::{Paper, Rock, Scissors},
@@ -76,7 +76,7 @@ impl StrategyPart<'_> {
 
 		let opponent_move: Move = self.0.parse().expect("failed to parse opponent move");
 
-		let desired_outcome: Outcome = match self.1 {
+		let desired_outcome: Outcome = match self.1.as_str() {
 			"X" => Loss,
 			"Y" => Draw,
 			"Z" => Win,
@@ -93,10 +93,10 @@ impl StrategyPart<'_> {
 	}
 }
 
-impl<'p> TryFrom<&'p str> for StrategyPart<'p> {
+impl TryFrom<&str> for StrategyPart {
 	type Error = Infallible;
 
-	fn try_from(str: &'p str) -> Result<Self, Self::Error> {
+	fn try_from(str: &str) -> Result<Self, Self::Error> {
 		let (left, right) = {
 			let mut split = str.split(' ');
 			(
@@ -105,15 +105,15 @@ impl<'p> TryFrom<&'p str> for StrategyPart<'p> {
 			)
 		};
 
-		Ok(Self(left, right))
+		Ok(Self(left.to_string(), right.to_string()))
 	}
 }
 
-pub type Intermediate<'i> = Vec<StrategyPart<'i>>;
+pub type Intermediate = Vec<StrategyPart>;
 pub type Output = u32;
 
 /// # Errors
-pub fn parse(str: &str) -> anyhow::Result<Intermediate<'_>> {
+pub fn parse(str: &str) -> anyhow::Result<Intermediate> {
 	Ok(
 		str
 			.lines()
@@ -154,4 +154,4 @@ mod part_two {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
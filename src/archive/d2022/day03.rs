@@ -1,22 +1,22 @@
 use {core::ops::Deref, std::collections::HashSet};
 
-pub struct Rucksack<'a>(&'a str);
+pub struct Rucksack(String);
 
-impl<'a> From<&'a str> for Rucksack<'a> {
-	fn from(value: &'a str) -> Self {
-		Self(value)
+impl From<&str> for Rucksack {
+	fn from(value: &str) -> Self {
+		Self(value.to_string())
 	}
 }
 
-impl<'a> Deref for Rucksack<'a> {
-	type Target = &'a str;
+impl Deref for Rucksack {
+	type Target = str;
 
 	fn deref(&self) -> &Self::Target {
 		&self.0
 	}
 }
 
-impl Rucksack<'_> {
+impl Rucksack {
 	fn char_priority(char: char) -> Option<u32> {
 		u8::try_from(char).ok().and_then(|byte| match byte {
 			b'a'..=b'z' => Some(u32::from((byte - b'a') + 1)),
@@ -39,15 +39,15 @@ impl Rucksack<'_> {
 	}
 
 	fn priorities(&self) -> HashSet<u32> {
-		Self::str_priorities(self.0)
+		Self::str_priorities(&self.0)
 	}
 }
 
-pub type Intermediate<'a> = Vec<Rucksack<'a>>;
+pub type Intermediate = Vec<Rucksack>;
 pub type Output = u32;
 
 /// # Errors
-pub fn parse(str: &str) -> anyhow::Result<Intermediate<'_>> {
+pub fn parse(str: &str) -> anyhow::Result<Intermediate> {
 	Ok(str.lines().map(Rucksack::from).collect())
 }
 
@@ -114,4 +114,4 @@ mod rucksack {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
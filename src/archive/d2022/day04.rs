@@ -77,4 +77,4 @@ pub fn part_two(assignments: &Intermediate) -> Option<Output> {
 	)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
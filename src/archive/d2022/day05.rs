@@ -205,4 +205,4 @@ pub fn part_two((state, moves): &Intermediate) -> Option<Output> {
 	Some(state.tops_concat())
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -93,4 +93,4 @@ crate::generate_example_tests!(parse, part_two,
 	part_two_example_4 | "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => Some(26),
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
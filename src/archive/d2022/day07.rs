@@ -228,4 +228,4 @@ pub fn part_two(tree: &Intermediate) -> Option<Output> {
 	}
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -141,4 +141,4 @@ fn part_two_example() {
 	);
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -274,4 +274,4 @@ crate::test_example!(
 	Some(36)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
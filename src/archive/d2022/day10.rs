@@ -21,7 +21,7 @@ impl FromStr for Instruction {
 	}
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum Output {
 	PartOne(u64),
 	PartTwo(String),
@@ -42,6 +42,15 @@ impl Display for Output {
 	}
 }
 
+impl From<Output> for crate::Answer {
+	fn from(output: Output) -> Self {
+		match output {
+			Output::PartOne(signal_strength) => signal_strength.into(),
+			Output::PartTwo(crt) => Self::Grid(crt),
+		}
+	}
+}
+
 impl Debug for Output {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		match self {
@@ -196,4 +205,4 @@ fn part_two_example() {
 	);
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -235,4 +235,4 @@ pub fn part_two(monkeys: &Intermediate) -> Option<Output> {
 	Some(monkey_business)
 }
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -372,4 +372,4 @@ crate::test_example!(
 	Some(140)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -1,9 +1,9 @@
-pub type Intermediate<'i> = Vec<&'i str>;
+pub type Intermediate = Vec<String>;
 pub type Output = u32;
 
 /// # Errors
-pub fn parse(input: &str) -> anyhow::Result<Intermediate<'_>> {
-	Ok(input.lines().collect())
+pub fn parse(input: &str) -> anyhow::Result<Intermediate> {
+	Ok(input.lines().map(str::to_string).collect())
 }
 
 const NUMBER_SUBSTITUTIONS: [(&str, &str); 9] = [
@@ -105,4 +105,4 @@ crate::test_example!(
 	Some(281)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -192,4 +192,4 @@ crate::test_example!(
 	Some(2286)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
@@ -169,4 +169,4 @@ crate::test_example!(
 	Some(467_835)
 );
 
-crate::generate_solver!(solve, =>, self);
+crate::generate_solver!(solver, =>, self);
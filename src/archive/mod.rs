@@ -1,18 +0,0 @@
-#[cfg(feature = "y2015")]
-pub(super) mod d2015;
-#[cfg(feature = "y2016")]
-pub(super) mod d2016;
-#[cfg(feature = "y2017")]
-pub(super) mod d2017;
-#[cfg(feature = "y2018")]
-pub(super) mod d2018;
-#[cfg(feature = "y2019")]
-pub(super) mod d2019;
-#[cfg(feature = "y2020")]
-pub(super) mod d2020;
-#[cfg(feature = "y2021")]
-pub(super) mod d2021;
-#[cfg(feature = "y2022")]
-pub(super) mod d2022;
-#[cfg(feature = "y2023")]
-pub(super) mod d2023;
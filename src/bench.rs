@@ -0,0 +1,194 @@
+use core::{error::Error, hint::black_box, time::Duration};
+use std::{io::Write, time::Instant};
+
+use crate::{Part, Solver, solver::solve_parts};
+
+/// How many times to run each phase of a solver when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+	/// Number of measured iterations.
+	pub iterations: usize,
+	/// Number of unmeasured iterations run beforehand to warm caches and the allocator.
+	pub warmup: usize,
+}
+
+impl BenchConfig {
+	pub const DEFAULT_WARMUP: usize = 3;
+}
+
+/// Summary statistics over the samples collected for a single phase.
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+	pub min: Duration,
+	pub median: Duration,
+	pub mean: Duration,
+	pub p95: Duration,
+}
+
+impl Summary {
+	/// Summarize `samples`, or return `None` if there are none.
+	pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+		let mut sorted = samples.to_vec();
+		sorted.sort_unstable();
+
+		let count = sorted.len();
+		let min = *sorted.first()?;
+
+		let median = if count.is_multiple_of(2) {
+			(sorted[count / 2 - 1] + sorted[count / 2]) / 2
+		} else {
+			sorted[count / 2]
+		};
+
+		let mean = sorted.iter().sum::<Duration>() / u32::try_from(count).ok()?;
+
+		// Nearest-rank percentile: the smallest sample that is at least 95% of the way through.
+		let p95 = sorted[(count * 95).div_ceil(100) - 1];
+
+		Some(Self {
+			min,
+			median,
+			mean,
+			p95,
+		})
+	}
+}
+
+/// The samples collected for each phase of a benchmarked solver.
+///
+/// Parts which returned no answer have no samples.
+#[derive(Debug, Default)]
+pub struct PhaseSamples {
+	pub parse: Vec<Duration>,
+	pub part_one: Vec<Duration>,
+	pub part_two: Vec<Duration>,
+}
+
+impl PhaseSamples {
+	fn part_mut(&mut self, part: Part) -> &mut Vec<Duration> {
+		match part {
+			Part::One => &mut self.part_one,
+			Part::Two => &mut self.part_two,
+		}
+	}
+}
+
+/// Repeatedly run the given `parts` of a `PartSolve`-class solver on `data`, creating a fresh instance for every
+/// iteration and calling `iterated` after each.
+///
+/// Returns `None` if `make_solver` does not produce a `PartSolve`-class solver, since "Original"-class solvers
+/// cannot be broken down into phases.
+pub fn bench_solver(
+	data: &str,
+	config: BenchConfig,
+	parts: &[Part],
+	mut make_solver: impl FnMut() -> Result<Solver, Box<dyn Error>>,
+	mut iterated: impl FnMut(),
+) -> Result<Option<PhaseSamples>, Box<dyn Error>> {
+	let mut samples = PhaseSamples::default();
+
+	for iteration in 0..config.warmup + config.iterations {
+		let Solver::PartSolve(mut part_solver) = make_solver()? else {
+			return Ok(None);
+		};
+
+		let measured = iteration >= config.warmup;
+
+		let t_start = Instant::now();
+		let intermediate = part_solver.parse(black_box(data))?;
+		let parse_time = t_start.elapsed();
+
+		if measured {
+			samples.parse.push(parse_time);
+		}
+
+		for (part, answer, part_time) in solve_parts(
+			part_solver.as_ref(),
+			intermediate.as_ref(),
+			parts,
+			&|_, _| {},
+		)
+		.answers
+		{
+			if measured && black_box(answer).is_answered() {
+				samples.part_mut(part).push(part_time);
+			}
+		}
+
+		iterated();
+	}
+
+	Ok(Some(samples))
+}
+
+/// Print a table summarizing each phase's samples.
+pub fn print_samples(samples: &PhaseSamples, out: &mut dyn Write) -> std::io::Result<()> {
+	writeln!(
+		out,
+		"  {:<8} {:>10} {:>10} {:>10} {:>10}",
+		"phase", "min", "median", "mean", "p95"
+	)?;
+
+	for (phase, phase_samples) in [
+		("parse", &samples.parse),
+		("part_one", &samples.part_one),
+		("part_two", &samples.part_two),
+	] {
+		match Summary::from_samples(phase_samples) {
+			Some(Summary {
+				min,
+				median,
+				mean,
+				p95,
+			}) => writeln!(
+				out,
+				"  {phase:<8} {:>10} {:>10} {:>10} {:>10}",
+				format!("{min:.1?}"),
+				format!("{median:.1?}"),
+				format!("{mean:.1?}"),
+				format!("{p95:.1?}"),
+			)?,
+			None => writeln!(out, "  {phase:<8} {:>10}", "n/a")?,
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+
+	use super::Summary;
+
+	fn millis(values: &[u64]) -> Vec<Duration> {
+		values.iter().copied().map(Duration::from_millis).collect()
+	}
+
+	#[test]
+	fn summary_of_nothing() {
+		assert_eq!(None, Summary::from_samples(&[]));
+	}
+
+	#[test]
+	fn summary_odd() {
+		assert_eq!(
+			Some(Summary {
+				min: Duration::from_millis(1),
+				median: Duration::from_millis(3),
+				mean: Duration::from_millis(4),
+				p95: Duration::from_millis(10),
+			}),
+			Summary::from_samples(&millis(&[10, 1, 3, 4, 2]))
+		);
+	}
+
+	#[test]
+	fn summary_even_and_p95() {
+		let samples = millis(&(1..=20).collect::<Vec<u64>>());
+		let summary = Summary::from_samples(&samples).unwrap();
+
+		assert_eq!(Duration::from_micros(10_500), summary.median);
+		assert_eq!(Duration::from_millis(19), summary.p95);
+	}
+}
@@ -0,0 +1,425 @@
+use std::collections::BTreeSet;
+
+use crate::{Part, options};
+
+/// Execution constraints controlling which solvers will be selected.
+///
+/// Constraints are built from command-line arguments, each of which is one of:
+///
+/// - a year (`2024`) or a day (`5`),
+/// - an inclusive range of years or days (`2015..=2017`, `2015-2017`, `1-10`), or an exclusive one (`1..11`),
+/// - a single year and day (`2024/5`),
+/// - `--all`, to run every solver when no years or days are given, or
+/// - `--part 1` or `--part 2`, to only run one part of each selected solver.
+#[derive(Debug, Clone, Default)]
+pub struct RunConstraints {
+	years: Option<BTreeSet<u16>>,
+	days: Option<BTreeSet<u8>>,
+	/// Individual (year, day) pairs, allowed regardless of the year/day constraints.
+	pairs: BTreeSet<(u16, u8)>,
+	/// If set, only these parts of each selected solver are run.
+	parts: Option<BTreeSet<Part>>,
+	/// If true and both year/day are None, run all solvers.
+	run_all_if_unconstrained: bool,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ConstraintsError {
+	#[error("unrecognized argument {token:?}; {hint}")]
+	UnknownArgument { token: String, hint: String },
+	#[error("{value} is neither a day (1-25) nor a year (2015 or later)")]
+	OutOfRange { value: String },
+	#[error("range {token:?} is empty")]
+	EmptyRange { token: String },
+	#[error("range {token:?} must span either days (1-25) or years (2015 or later)")]
+	MixedRange { token: String },
+	#[error("missing value for {flag}")]
+	MissingValue { flag: String },
+	#[error("invalid part {value:?}; expected 1 or 2")]
+	InvalidPart { value: String },
+}
+
+/// Describes the accepted argument forms, for errors about arguments which match none of them.
+const GRAMMAR_HINT: &str = "expected a year (2024), a day (5), a range (2015..=2017, 1-10), a year/day pair (2024/5), \
+	--all, or --part 1|2";
+
+const FLAGS: &[&str] = &["--all", "--part"];
+
+/// A single positional argument selecting some solvers.
+#[derive(Debug, PartialEq)]
+enum Selector {
+	Year(u16),
+	Day(u8),
+	Years(BTreeSet<u16>),
+	Days(BTreeSet<u8>),
+	Pair(u16, u8),
+}
+
+enum Number {
+	Day(u8),
+	Year(u16),
+}
+
+fn classify(value: &str) -> Result<Number, ConstraintsError> {
+	let Ok(number) = value.parse::<u16>() else {
+		return Err(ConstraintsError::UnknownArgument {
+			token: value.to_string(),
+			hint: GRAMMAR_HINT.to_string(),
+		});
+	};
+
+	match (number, u8::try_from(number)) {
+		(_, Ok(day)) if (1..=25).contains(&day) => Ok(Number::Day(day)),
+		(year, _) if (2015..).contains(&year) => Ok(Number::Year(year)),
+		_ => Err(ConstraintsError::OutOfRange {
+			value: value.to_string(),
+		}),
+	}
+}
+
+impl core::str::FromStr for Selector {
+	type Err = ConstraintsError;
+
+	fn from_str(token: &str) -> Result<Self, Self::Err> {
+		let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+
+		// `2024/5`
+		if let Some((year, day)) = token.split_once('/') {
+			return match (classify(year)?, classify(day)?) {
+				(Number::Year(year), Number::Day(day)) => Ok(Selector::Pair(year, day)),
+				_ => Err(ConstraintsError::UnknownArgument {
+					token: token.to_string(),
+					hint: "a year/day pair is written as YEAR/DAY, e.g. 2024/5".to_string(),
+				}),
+			};
+		}
+
+		// `2015..=2017`, `1..11` or `1-10`
+		let range = token
+			.split_once("..=")
+			.map(|(start, end)| (start, end, true))
+			.or_else(|| {
+				token
+					.split_once("..")
+					.map(|(start, end)| (start, end, false))
+			})
+			.or_else(|| token.split_once('-').map(|(start, end)| (start, end, true)))
+			.filter(|(start, end, _)| all_digits(start) && all_digits(end));
+
+		if let Some((start, end, inclusive)) = range {
+			return match (classify(start)?, classify(end)?) {
+				(Number::Day(start), Number::Day(end)) => {
+					let days: BTreeSet<u8> = if inclusive {
+						(start..=end).collect()
+					} else {
+						(start..end).collect()
+					};
+
+					if days.is_empty() {
+						Err(ConstraintsError::EmptyRange {
+							token: token.to_string(),
+						})
+					} else {
+						Ok(Selector::Days(days))
+					}
+				}
+				(Number::Year(start), Number::Year(end)) => {
+					let years: BTreeSet<u16> = if inclusive {
+						(start..=end).collect()
+					} else {
+						(start..end).collect()
+					};
+
+					if years.is_empty() {
+						Err(ConstraintsError::EmptyRange {
+							token: token.to_string(),
+						})
+					} else {
+						Ok(Selector::Years(years))
+					}
+				}
+				_ => Err(ConstraintsError::MixedRange {
+					token: token.to_string(),
+				}),
+			};
+		}
+
+		match classify(token)? {
+			Number::Day(day) => Ok(Selector::Day(day)),
+			Number::Year(year) => Ok(Selector::Year(year)),
+		}
+	}
+}
+
+/// Levenshtein distance between two strings, used to suggest corrections for mistyped flags.
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+	for (i, ca) in a.chars().enumerate() {
+		let mut current = vec![i + 1];
+
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(ca != *cb);
+			current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+		}
+
+		previous = current;
+	}
+
+	previous[b.len()]
+}
+
+fn unknown_flag(flag: &str) -> ConstraintsError {
+	let closest = FLAGS
+		.iter()
+		.chain(options::FLAGS)
+		.map(|known| (edit_distance(flag, known), known))
+		.min();
+
+	let hint = match closest {
+		Some((distance, known)) if distance <= 2 => format!("did you mean {known}?"),
+		_ => GRAMMAR_HINT.to_string(),
+	};
+
+	ConstraintsError::UnknownArgument {
+		token: flag.to_string(),
+		hint,
+	}
+}
+
+impl RunConstraints {
+	/// Determine constraints from command-line arguments.
+	///
+	/// Every argument must be understood; anything else is reported as an error rather than ignored.
+	pub fn parse_from_args(args: impl IntoIterator<Item = String>) -> Result<Self, ConstraintsError> {
+		let mut constraints = Self::default();
+
+		let mut args = args.into_iter();
+
+		while let Some(arg) = args.next() {
+			let (flag, inline_value) = match arg.split_once('=') {
+				Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+				_ => (arg.as_str(), None),
+			};
+
+			match flag {
+				"--all" if inline_value.is_none() => constraints.run_all_if_unconstrained = true,
+				"--part" => {
+					let value =
+						inline_value
+							.or_else(|| args.next())
+							.ok_or_else(|| ConstraintsError::MissingValue {
+								flag: flag.to_string(),
+							})?;
+
+					let part = match value.as_str() {
+						"1" => Part::One,
+						"2" => Part::Two,
+						_ => return Err(ConstraintsError::InvalidPart { value }),
+					};
+
+					constraints
+						.parts
+						.get_or_insert_with(BTreeSet::new)
+						.insert(part);
+				}
+				flag if flag.starts_with('-') => {
+					return Err(unknown_flag(flag));
+				}
+				_ => constraints.select(arg.parse()?),
+			}
+		}
+
+		Ok(constraints)
+	}
+
+	fn select(&mut self, selector: Selector) {
+		match selector {
+			Selector::Year(year) => {
+				self.years.get_or_insert_with(BTreeSet::new).insert(year);
+			}
+			Selector::Day(day) => {
+				self.days.get_or_insert_with(BTreeSet::new).insert(day);
+			}
+			Selector::Years(years) => self.years.get_or_insert_with(BTreeSet::new).extend(years),
+			Selector::Days(days) => self.days.get_or_insert_with(BTreeSet::new).extend(days),
+			Selector::Pair(year, day) => {
+				self.pairs.insert((year, day));
+			}
+		}
+	}
+
+	/// Constraints which allow only the solver for a single year and day.
+	pub fn exactly(year: u16, day: u8) -> Self {
+		Self {
+			pairs: BTreeSet::from([(year, day)]),
+			..Self::default()
+		}
+	}
+
+	/// Returns true if nothing at all has been selected.
+	pub fn is_empty(&self) -> bool {
+		!self.run_all_if_unconstrained
+			&& self.years.is_none()
+			&& self.days.is_none()
+			&& self.pairs.is_empty()
+	}
+
+	/// These constraints, or constraints allowing every solver if nothing was selected.
+	#[must_use]
+	pub fn or_all(mut self) -> Self {
+		if self.is_empty() {
+			self.run_all_if_unconstrained = true;
+		}
+
+		self
+	}
+
+	/// Returns true if a solver for a particular year and day is allowed under this set of constraints.
+	pub fn allows(&self, year: u16, day: u8) -> bool {
+		if self.pairs.contains(&(year, day)) {
+			return true;
+		}
+
+		match (self.run_all_if_unconstrained, &self.years, &self.days) {
+			// If no year/day specified, fall back to run_all_if_unconstrained.
+			(true, None, None) => self.pairs.is_empty(),
+			(false, None, None) => false,
+			// If either year or day is specified, match against what was given (could be odd in the day case).
+			(_, Some(req_year), None) => req_year.contains(&year),
+			(_, None, Some(req_day)) => req_day.contains(&day),
+			// If both year and day are specified, use both constraints (strictest).
+			(_, Some(req_year), Some(req_day)) => req_year.contains(&year) && req_day.contains(&day),
+		}
+	}
+
+	/// The parts of each selected solver which should be run.
+	pub fn selected_parts(&self) -> Vec<Part> {
+		Part::ALL
+			.into_iter()
+			.filter(|part| self.parts.as_ref().is_none_or(|parts| parts.contains(part)))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeSet;
+
+	use super::{ConstraintsError, Part, RunConstraints, Selector};
+
+	fn parse(args: &[&str]) -> Result<RunConstraints, ConstraintsError> {
+		RunConstraints::parse_from_args(args.iter().map(ToString::to_string))
+	}
+
+	#[test]
+	fn selectors() {
+		assert_eq!(Ok(Selector::Year(2024)), "2024".parse());
+		assert_eq!(Ok(Selector::Day(5)), "5".parse());
+		assert_eq!(Ok(Selector::Pair(2024, 5)), "2024/5".parse());
+		assert_eq!(
+			Ok(Selector::Years(BTreeSet::from([2015, 2016, 2017]))),
+			"2015..=2017".parse()
+		);
+		assert_eq!(
+			Ok(Selector::Years(BTreeSet::from([2015, 2016]))),
+			"2015..2017".parse()
+		);
+		assert_eq!(Ok(Selector::Days((1..=10).collect())), "1-10".parse());
+	}
+
+	#[test]
+	fn selector_errors() {
+		assert_eq!(
+			Err(ConstraintsError::OutOfRange {
+				value: "26".to_string()
+			}),
+			"26".parse::<Selector>()
+		);
+		assert!(matches!(
+			"5..=2017".parse::<Selector>(),
+			Err(ConstraintsError::MixedRange { .. })
+		));
+		assert!(matches!(
+			"10-1".parse::<Selector>(),
+			Err(ConstraintsError::EmptyRange { .. })
+		));
+		assert!(matches!(
+			"5/2024".parse::<Selector>(),
+			Err(ConstraintsError::UnknownArgument { .. })
+		));
+		assert!(matches!(
+			"day5".parse::<Selector>(),
+			Err(ConstraintsError::UnknownArgument { .. })
+		));
+	}
+
+	#[test]
+	fn unknown_day_is_an_error() {
+		assert!(matches!(
+			parse(&["2024", "26"]),
+			Err(ConstraintsError::OutOfRange { .. })
+		));
+	}
+
+	#[test]
+	fn suggests_flags() {
+		assert_eq!(
+			Err(ConstraintsError::UnknownArgument {
+				token: "--al".to_string(),
+				hint: "did you mean --all?".to_string()
+			}),
+			parse(&["--al"]).map(|_| ())
+		);
+		assert!(matches!(
+			parse(&["--jbos"]),
+			Err(ConstraintsError::UnknownArgument { hint, .. }) if hint == "did you mean --jobs?"
+		));
+	}
+
+	#[test]
+	fn allows() {
+		let constraints = parse(&["2015-2016", "1-2", "2024/5"]).unwrap();
+
+		assert!(constraints.allows(2015, 1));
+		assert!(constraints.allows(2016, 2));
+		assert!(constraints.allows(2024, 5));
+		assert!(!constraints.allows(2024, 1));
+		assert!(!constraints.allows(2017, 1));
+
+		let constraints = parse(&["2024/5"]).unwrap();
+		assert!(constraints.allows(2024, 5));
+		assert!(!constraints.allows(2024, 6));
+	}
+
+	#[test]
+	fn all_and_empty() {
+		assert!(parse(&[]).unwrap().is_empty());
+		assert!(!parse(&[]).unwrap().allows(2024, 1));
+
+		let constraints = parse(&["--all"]).unwrap();
+		assert!(!constraints.is_empty());
+		assert!(constraints.allows(2015, 1));
+	}
+
+	#[test]
+	fn parts() {
+		assert_eq!(
+			vec![Part::One, Part::Two],
+			parse(&[]).unwrap().selected_parts()
+		);
+		assert_eq!(
+			vec![Part::Two],
+			parse(&["--part", "2"]).unwrap().selected_parts()
+		);
+		assert_eq!(
+			vec![Part::One],
+			parse(&["--part=1"]).unwrap().selected_parts()
+		);
+		assert!(matches!(
+			parse(&["--part", "3"]),
+			Err(ConstraintsError::InvalidPart { .. })
+		));
+	}
+}
@@ -1,12 +1,16 @@
 use std::collections::BTreeMap;
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver};
 
 #[derive(Default)]
 struct Solution;
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Vec<(i32, i32)>;
+	type PartOne = i32;
+	type PartTwo = i32;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
 		let lines = input.lines().map(|line| {
 			let numbers = line
 				.split_whitespace()
@@ -18,73 +22,50 @@ impl PartSolve for Solution {
 			(numbers[0], numbers[1])
 		});
 
-		Ok(Box::new(lines.collect::<Vec<_>>()))
+		Ok(lines.collect())
 	}
 
-	fn part_one(&self, pairs: &dyn core::any::Any) -> Option<String> {
-		let (left, right) = pairs
-			.downcast_ref::<Vec<(i32, i32)>>()
-			// Unpack the pairs into two separate left/right lists.
-			.map(|pairs| {
-				let mut left = Vec::with_capacity(pairs.len());
-				let mut right = Vec::with_capacity(pairs.len());
-
-				for (l, r) in pairs {
-					left.push(*l);
-					right.push(*r);
-				}
-
-				(left, right)
-			})
-			// Sort the lists.
-			.map(|(mut left, mut right)| {
-				left.sort_unstable();
-				right.sort_unstable();
-
-				(left, right)
-			})
-			.inspect(|(left, right)| {
-				debug_assert_eq!(left.len(), right.len());
-			})?;
+	fn part_one(&self, pairs: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		// Unpack the pairs into two separate left/right lists.
+		let mut left = Vec::with_capacity(pairs.len());
+		let mut right = Vec::with_capacity(pairs.len());
+
+		for (l, r) in pairs {
+			left.push(*l);
+			right.push(*r);
+		}
+
+		// Sort the lists.
+		left.sort_unstable();
+		right.sort_unstable();
+
+		debug_assert_eq!(left.len(), right.len());
 
 		// Compute the sum of differences.
-		Some(
+		Ok(Some(
 			left
 				.iter()
 				.zip(right.iter())
 				.map(|(l, r)| (r - l).abs())
-				.sum::<i32>()
-				.to_string(),
-		)
+				.sum::<i32>(),
+		))
 	}
 
-	fn part_two(&self, pairs: &dyn core::any::Any) -> Option<String> {
-		let (list, occurrences) = pairs.downcast_ref::<Vec<(i32, i32)>>().map(|pairs| {
-			let mut left = Vec::with_capacity(pairs.len());
-			let mut right: BTreeMap<i32, i32> = BTreeMap::new();
+	fn part_two(&self, pairs: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		let mut list = Vec::with_capacity(pairs.len());
+		let mut occurrences: BTreeMap<i32, i32> = BTreeMap::new();
 
-			// Left: Unstructure like Part One.
-			// Right: Want BTreeMap<i32, i32> where i32 increments every time number appears in right list.
-			for (l, r) in pairs {
-				left.push(*l);
-				*right.entry(*r).or_insert(0_i32) += 1;
-			}
+		// Left: Unstructure like Part One.
+		// Right: Want BTreeMap<i32, i32> where i32 increments every time number appears in right list.
+		for (l, r) in pairs {
+			list.push(*l);
+			*occurrences.entry(*r).or_insert(0_i32) += 1;
+		}
 
-			(left, right)
-		})?;
-
-		Some(
-			list
-				.iter()
-				.fold(0_i32, |score, &number| {
-					score + occurrences.get(&number).unwrap_or(&0) * number
-				})
-				.to_string(),
-		)
+		Ok(Some(list.iter().fold(0_i32, |score, &number| {
+			score + occurrences.get(&number).unwrap_or(&0) * number
+		})))
 	}
 }
 
 export_solver!(solver, Solver::PartSolve(Box::new(Solution)));
-
-part_test!(part_one, Solution, file "day01.example.in.txt", part_one, file "day01.example.out-1.txt");
-part_test!(part_two, Solution, file "day01.example.in.txt", part_two, file "day01.example.out-2.txt");
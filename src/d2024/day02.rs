@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution {
@@ -33,8 +33,12 @@ impl Solution {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, reports: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = ();
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(&mut self, reports: &str) -> anyhow::Result<Self::Intermediate> {
 		self.reports = Some(
 			reports
 				.lines()
@@ -47,35 +51,33 @@ impl PartSolve for Solution {
 				.collect(),
 		);
 
-		Ok(Box::new(()))
+		Ok(())
 	}
 
-	fn part_one(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
+	fn part_one(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let Some(reports) = &self.reports else {
-			return None;
+			return Ok(None);
 		};
 
-		Some(
+		Ok(Some(
 			reports
 				.iter()
 				.filter(|report| Solution::report_is_safe_as_is(report))
-				.count()
-				.to_string(),
-		)
+				.count(),
+		))
 	}
 
-	fn part_two(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
+	fn part_two(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let Some(reports) = &self.reports else {
-			return None;
+			return Ok(None);
 		};
 
-		Some(
+		Ok(Some(
 			reports
 				.iter()
 				.filter(|report| Solution::report_is_safe_after_removing_at_most_one_element(report))
-				.count()
-				.to_string(),
-		)
+				.count(),
+		))
 	}
 }
 
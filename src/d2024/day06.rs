@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Concurrent, Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 enum Direction {
@@ -222,26 +222,26 @@ fn neighbors((x, y): (usize, usize)) -> HashSet<(usize, usize)> {
 	.collect()
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
-		Ok(Box::new(input.parse::<Grid>()?))
-	}
+impl TypedPartSolve for Solution {
+	type Intermediate = Grid;
+	type PartOne = usize;
+	type PartTwo = usize;
 
-	fn part_one(&self, grid: &dyn core::any::Any) -> Option<String> {
-		let grid = grid.downcast_ref::<Grid>()?;
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+		input.parse::<Grid>()
+	}
 
+	fn part_one(&self, grid: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let mut state = SolverState::from(grid);
 
 		while state.position.is_some() {
 			state.tick();
 		}
 
-		Some(state.count_visited().to_string())
+		Ok(Some(state.count_visited()))
 	}
 
-	fn part_two(&self, grid: &dyn core::any::Any) -> Option<String> {
-		let grid = grid.downcast_ref::<Grid>()?;
-
+	fn part_two(&self, grid: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let mut state = SolverState::from(grid);
 
 		while state.position.is_some() {
@@ -276,11 +276,11 @@ impl PartSolve for Solution {
 			})
 			.count();
 
-		Some(candidates_that_form_a_loop.to_string())
+		Ok(Some(candidates_that_form_a_loop))
 	}
 }
 
-export_solver!(solver, Solver::PartSolve(Box::new(Solution)));
+export_solver!(solver, Solver::PartSolve(Box::new(Concurrent(Solution))));
 
 part_test!(part_one, Solution, file "day06.example.in.txt", part_one, literal "41");
 
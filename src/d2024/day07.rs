@@ -1,6 +1,6 @@
 use itertools::{Itertools, repeat_n};
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -92,8 +92,12 @@ fn generate_possible_expressions(
 		})
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, equations: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Vec<(u64, Vec<u64>)>;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(&mut self, equations: &str) -> anyhow::Result<Self::Intermediate> {
 		let equations: Vec<(u64, Vec<u64>)> = equations
 			.lines()
 			.map(|line| {
@@ -118,12 +122,10 @@ impl PartSolve for Solution {
 			})
 			.collect();
 
-		Ok(Box::new(equations))
+		Ok(equations)
 	}
 
-	fn part_one(&self, equations: &dyn core::any::Any) -> Option<String> {
-		let equations = equations.downcast_ref::<Vec<(u64, Vec<u64>)>>()?;
-
+	fn part_one(&self, equations: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let mut sum: u64 = 0;
 
 		for (lhs, rhs_numbers) in equations {
@@ -137,12 +139,10 @@ impl PartSolve for Solution {
 			}
 		}
 
-		Some(sum.to_string())
+		Ok(Some(sum))
 	}
 
-	fn part_two(&self, equations: &dyn core::any::Any) -> Option<String> {
-		let equations = equations.downcast_ref::<Vec<(u64, Vec<u64>)>>()?;
-
+	fn part_two(&self, equations: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let mut sum: u64 = 0;
 
 		for (lhs, rhs_numbers) in equations {
@@ -157,7 +157,7 @@ impl PartSolve for Solution {
 			}
 		}
 
-		Some(sum.to_string())
+		Ok(Some(sum))
 	}
 }
 
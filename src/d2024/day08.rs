@@ -2,7 +2,7 @@ use std::collections::{BTreeSet, HashMap};
 
 use itertools::Itertools;
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -48,8 +48,12 @@ impl Map {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, map: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Map;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(&mut self, map: &str) -> anyhow::Result<Self::Intermediate> {
 		let map_chars: Vec<Vec<char>> = map
 			.lines()
 			.map(move |line| line.chars().collect())
@@ -93,12 +97,10 @@ impl PartSolve for Solution {
 			antinodes,
 		};
 
-		Ok(Box::new(map))
+		Ok(map)
 	}
 
-	fn part_one(&self, map: &dyn core::any::Any) -> Option<String> {
-		let map = map.downcast_ref::<Map>()?;
-
+	fn part_one(&self, map: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let mut map: Map = map.to_owned();
 
 		// Read from map.antennas, write to map.antinodes.
@@ -135,12 +137,10 @@ impl PartSolve for Solution {
 			}
 		}
 
-		Some(map.antinodes.len().to_string())
+		Ok(Some(map.antinodes.len()))
 	}
 
-	fn part_two(&self, map: &dyn core::any::Any) -> Option<String> {
-		let map = map.downcast_ref::<Map>()?;
-
+	fn part_two(&self, map: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let mut map: Map = map.to_owned();
 
 		// Read from map.antennas, write to map.antinodes.
@@ -180,7 +180,7 @@ impl PartSolve for Solution {
 			}
 		}
 
-		Some(map.antinodes.len().to_string())
+		Ok(Some(map.antinodes.len()))
 	}
 }
 
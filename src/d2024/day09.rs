@@ -1,7 +1,7 @@
 use core::ops::RangeInclusive;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -315,8 +315,12 @@ impl From<Vec<DiskMapDescriptor>> for Disk {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, disk_map_str: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Vec<DiskMapDescriptor>;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(&mut self, disk_map_str: &str) -> anyhow::Result<Self::Intermediate> {
 		let disk_map: Vec<u8> = disk_map_str
 			.trim()
 			.chars()
@@ -345,11 +349,10 @@ impl PartSolve for Solution {
 			})
 			.collect();
 
-		Ok(Box::new(disk_map))
+		Ok(disk_map)
 	}
 
-	fn part_one(&self, disk_map: &dyn core::any::Any) -> Option<String> {
-		let disk_map: &Vec<DiskMapDescriptor> = disk_map.downcast_ref::<Vec<DiskMapDescriptor>>()?;
+	fn part_one(&self, disk_map: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let disk_map: Vec<DiskMapDescriptor> = disk_map.clone();
 
 		let _total_size = disk_map.iter().fold(0usize, |acc, entry| {
@@ -364,17 +367,16 @@ impl PartSolve for Solution {
 		let mut disk = Disk::from(disk_map);
 		disk.compactify_bad(false);
 
-		Some(disk.checksum().to_string())
+		Ok(Some(disk.checksum()))
 	}
 
-	fn part_two(&self, disk_map: &dyn core::any::Any) -> Option<String> {
-		let disk_map: &Vec<DiskMapDescriptor> = disk_map.downcast_ref::<Vec<DiskMapDescriptor>>()?;
+	fn part_two(&self, disk_map: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let disk_map: Vec<DiskMapDescriptor> = disk_map.clone();
 
 		let mut disk = Disk::from(disk_map);
 		disk.compactify_better(false);
 
-		Some(disk.checksum().to_string())
+		Ok(Some(disk.checksum()))
 	}
 }
 
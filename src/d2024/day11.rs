@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -109,8 +109,12 @@ impl RockLine {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, arrangement: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = RockLine;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(&mut self, arrangement: &str) -> anyhow::Result<Self::Intermediate> {
 		let stones = arrangement
 			.split_ascii_whitespace()
 			.map(|rock| rock.parse::<u64>().expect("failed to parse rock number"))
@@ -120,11 +124,10 @@ impl PartSolve for Solution {
 				acc
 			});
 
-		Ok(Box::new(RockLine { stones }))
+		Ok(RockLine { stones })
 	}
 
-	fn part_one(&self, rock_line: &dyn core::any::Any) -> Option<String> {
-		let rock_line = rock_line.downcast_ref::<RockLine>()?;
+	fn part_one(&self, rock_line: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let mut rock_line: RockLine = (*rock_line).clone();
 
 		let mut cache: HashMap<Stone, Replacement> = HashMap::new();
@@ -133,11 +136,10 @@ impl PartSolve for Solution {
 			rock_line.blink(&mut cache);
 		}
 
-		Some(rock_line.count().to_string())
+		Ok(Some(rock_line.count()))
 	}
 
-	fn part_two(&self, rock_line: &dyn core::any::Any) -> Option<String> {
-		let rock_line = rock_line.downcast_ref::<RockLine>()?;
+	fn part_two(&self, rock_line: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let mut rock_line: RockLine = (*rock_line).clone();
 
 		let mut cache: HashMap<Stone, Replacement> = HashMap::new();
@@ -146,7 +148,7 @@ impl PartSolve for Solution {
 			rock_line.blink(&mut cache);
 		}
 
-		Some(rock_line.count().to_string())
+		Ok(Some(rock_line.count()))
 	}
 }
 
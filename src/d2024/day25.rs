@@ -1,19 +1,23 @@
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
 
-impl PartSolve for Solution {
-	fn parse(&mut self, _input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
-		Ok(Box::new(()))
+impl TypedPartSolve for Solution {
+	type Intermediate = ();
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(&mut self, _input: &str) -> anyhow::Result<Self::Intermediate> {
+		Ok(())
 	}
 
-	fn part_one(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
-		None
+	fn part_one(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		Ok(None)
 	}
 
-	fn part_two(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
-		None
+	fn part_two(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		Ok(None)
 	}
 }
 
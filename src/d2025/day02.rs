@@ -1,6 +1,6 @@
 use core::ops::RangeInclusive;
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 trait ValidationExt {
 	fn is_valid_id(&self) -> bool;
@@ -96,8 +96,12 @@ fn range_1122_valid() {
 #[derive(Default)]
 struct Solution;
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Vec<RangeInclusive<u64>>;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
 		let ranges = input
 			.split(',')
 			.map(str::trim)
@@ -109,25 +113,20 @@ impl PartSolve for Solution {
 			})
 			.collect::<Vec<RangeInclusive<u64>>>();
 
-		Ok(Box::new(ranges))
+		Ok(ranges)
 	}
 
-	fn part_one(&self, ranges: &dyn core::any::Any) -> Option<String> {
-		let ranges = ranges.downcast_ref::<Vec<RangeInclusive<u64>>>()?;
-
-		Some(
+	fn part_one(&self, ranges: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		Ok(Some(
 			ranges
 				.iter()
 				.map(|range| range.clone().filter(|id| !id.is_valid_id()).sum::<u64>())
-				.sum::<u64>()
-				.to_string(),
-		)
+				.sum::<u64>(),
+		))
 	}
 
-	fn part_two(&self, ranges: &dyn core::any::Any) -> Option<String> {
-		let ranges = ranges.downcast_ref::<Vec<RangeInclusive<u64>>>()?;
-
-		Some(
+	fn part_two(&self, ranges: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		Ok(Some(
 			ranges
 				.iter()
 				.map(|range| {
@@ -136,9 +135,8 @@ impl PartSolve for Solution {
 						.filter(|id| !id.is_valid_id_general())
 						.sum::<u64>()
 				})
-				.sum::<u64>()
-				.to_string(),
-		)
+				.sum::<u64>(),
+		))
 	}
 }
 
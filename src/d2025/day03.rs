@@ -1,4 +1,4 @@
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -49,8 +49,12 @@ fn find_max_joltage(bank: &[u8], n_batteries: usize) -> u64 {
 	dp[0][n_batteries].expect("failed to assemble a valid joltage")
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Vec<Vec<u8>>;
+	type PartOne = u32;
+	type PartTwo = u64;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
 		let banks: Vec<Vec<u8>> = input
 			.lines()
 			.map(|line| {
@@ -88,30 +92,22 @@ impl PartSolve for Solution {
 			"All banks should have the same number of entries"
 		);
 
-		Ok(Box::new(banks))
+		Ok(banks)
 	}
 
-	fn part_one(&self, banks: &dyn core::any::Any) -> Option<String> {
-		let banks = banks
-			.downcast_ref::<Vec<Vec<u8>>>()
-			.expect("Failed to downcast banks");
-
+	fn part_one(&self, banks: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
 		let total_max_joltage: u32 = banks
 			.iter()
 			.map(|bank| u32::try_from(find_max_joltage(bank, 2)).expect("joltage out of range"))
 			.sum();
 
-		Some(total_max_joltage.to_string())
+		Ok(Some(total_max_joltage))
 	}
 
-	fn part_two(&self, banks: &dyn core::any::Any) -> Option<String> {
-		let banks = banks
-			.downcast_ref::<Vec<Vec<u8>>>()
-			.expect("Failed to downcast banks");
-
+	fn part_two(&self, banks: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
 		let total_max_joltage: u64 = banks.iter().map(|bank| find_max_joltage(bank, 12)).sum();
 
-		Some(total_max_joltage.to_string())
+		Ok(Some(total_max_joltage))
 	}
 }
 
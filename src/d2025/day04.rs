@@ -1,58 +1,27 @@
 use core::str::FromStr;
 
-use crate::{PartSolve, Solver, export_solver, part_test, util::neighbors};
+use crate::{
+	Solver, TypedPartSolve, export_solver, part_test,
+	util::grid::{Grid, Position},
+};
 
 #[derive(Default)]
 struct Solution;
 
+/// Whether each position holds a roll of paper.
 #[derive(Clone)]
-struct Grid {
-	rolls: Vec<Vec<bool>>,
-}
-
-impl Grid {
-	const fn char_to_roll_value(c: char) -> bool {
-		matches!(c, '@')
-	}
+struct Rolls(Grid<bool>);
 
-	fn roll_exists_at(&self, x: i32, y: i32) -> bool {
-		if x < 0 || y < 0 {
-			return false;
-		}
-
-		let x = usize::try_from(x).unwrap();
-		let y = usize::try_from(y).unwrap();
-
-		if y >= self.rolls.len() || x >= self.rolls[y].len() {
-			return false;
-		}
-
-		self.rolls[y][x]
-	}
-
-	fn accessible_rolls(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+impl Rolls {
+	fn accessible_rolls(&self) -> impl Iterator<Item = Position> + '_ {
 		// The forklifts can only access a roll of paper if there are *fewer than four rolls of paper in the eight adjacent positions*.
-		self.rolls.iter().enumerate().flat_map(move |(y, row)| {
-			row.iter().enumerate().filter_map(move |(x, &cell)| {
-				if cell {
-					let mut num_adjacent_rolls = 0;
-
-					let x_i32 = i32::try_from(x).expect("x out of range");
-					let y_i32 = i32::try_from(y).expect("y out of range");
-
-					for (neighbor_x, neighbor_y) in neighbors::get(&(x_i32, y_i32)) {
-						if self.roll_exists_at(neighbor_x, neighbor_y) {
-							num_adjacent_rolls += 1;
-						}
-					}
-
-					if num_adjacent_rolls < 4 {
-						return Some((x, y));
-					}
-				}
-
-				None
-			})
+		self.0.find_all(|&roll| roll).filter(|&position| {
+			self
+				.0
+				.all_neighbors(position)
+				.filter(|&neighbor| self.0[neighbor])
+				.count()
+				< 4
 		})
 	}
 
@@ -61,15 +30,13 @@ impl Grid {
 	}
 
 	fn remove_accessible_rolls_once(&mut self) -> usize {
-		let mut removed_count = 0;
-		let accessible_rolls: Vec<(usize, usize)> = self.accessible_rolls().collect();
+		let accessible_rolls: Vec<Position> = self.accessible_rolls().collect();
 
-		for (x, y) in accessible_rolls {
-			self.rolls[y][x] = false;
-			removed_count += 1;
+		for &position in &accessible_rolls {
+			self.0[position] = false;
 		}
 
-		removed_count
+		accessible_rolls.len()
 	}
 
 	fn remove_all_accessible_rolls_iteratively(&mut self) -> usize {
@@ -87,35 +54,35 @@ impl Grid {
 	}
 }
 
-impl FromStr for Grid {
+impl FromStr for Rolls {
 	type Err = anyhow::Error;
 
 	fn from_str(str: &str) -> Result<Self, Self::Err> {
 		// In the grid, @ represents a roll, . represents an empty space.
-
-		let rolls = str
-			.lines()
-			.map(|line| line.chars().map(Grid::char_to_roll_value).collect())
-			.collect();
-
-		Ok(Grid { rolls })
+		Ok(Rolls(Grid::parse(str, |c| match c {
+			'@' => Some(true),
+			'.' => Some(false),
+			_ => None,
+		})?))
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
-		Ok(Box::new(Grid::from_str(input)?))
+impl TypedPartSolve for Solution {
+	type Intermediate = Rolls;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+		Rolls::from_str(input)
 	}
 
-	fn part_one(&self, grid: &dyn core::any::Any) -> Option<String> {
-		let grid: &Grid = grid.downcast_ref::<Grid>()?;
-		Some(grid.num_accessible_rolls().to_string())
+	fn part_one(&self, rolls: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		Ok(Some(rolls.num_accessible_rolls()))
 	}
 
-	fn part_two(&self, grid: &dyn core::any::Any) -> Option<String> {
-		let grid: &Grid = grid.downcast_ref::<Grid>()?;
-		let mut grid = grid.clone();
-		Some(grid.remove_all_accessible_rolls_iteratively().to_string())
+	fn part_two(&self, rolls: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		let mut rolls = rolls.clone();
+		Ok(Some(rolls.remove_all_accessible_rolls_iteratively()))
 	}
 }
 
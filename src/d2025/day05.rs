@@ -1,7 +1,7 @@
 use core::str::FromStr;
 use std::collections::BTreeSet;
 
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -101,19 +101,29 @@ impl IngredientDatabase {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
-		Ok(Box::new(IngredientDatabase::from_str(input)?))
+impl TypedPartSolve for Solution {
+	type Intermediate = IngredientDatabase;
+	type PartOne = usize;
+	type PartTwo = usize;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+		IngredientDatabase::from_str(input)
 	}
 
-	fn part_one(&self, ingredient_database: &dyn core::any::Any) -> Option<String> {
-		let db = ingredient_database.downcast_ref::<IngredientDatabase>()?;
-		Some(db.count_fresh_listed_ingredients().to_string())
+	fn part_one(
+		&self,
+		ingredient_database: &Self::Intermediate,
+	) -> anyhow::Result<Option<Self::PartOne>> {
+		let db = ingredient_database;
+		Ok(Some(db.count_fresh_listed_ingredients()))
 	}
 
-	fn part_two(&self, ingredient_database: &dyn core::any::Any) -> Option<String> {
-		let db = ingredient_database.downcast_ref::<IngredientDatabase>()?;
-		Some(db.count_combined_overlap_ranges().to_string())
+	fn part_two(
+		&self,
+		ingredient_database: &Self::Intermediate,
+	) -> anyhow::Result<Option<Self::PartTwo>> {
+		let db = ingredient_database;
+		Ok(Some(db.count_combined_overlap_ranges()))
 	}
 }
 
@@ -1,4 +1,4 @@
-use crate::{PartSolve, Solver, export_solver, part_test};
+use crate::{Solver, TypedPartSolve, export_solver, part_test};
 
 #[derive(Default)]
 struct Solution;
@@ -53,8 +53,12 @@ impl Homework {
 	}
 }
 
-impl PartSolve for Solution {
-	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+impl TypedPartSolve for Solution {
+	type Intermediate = Homework;
+	type PartOne = u64;
+	type PartTwo = u64;
+
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
 		let lines = input.lines().map(str::trim).collect::<Vec<_>>();
 
 		let (operator_line, number_rows) = lines.split_last().unwrap();
@@ -79,16 +83,15 @@ impl PartSolve for Solution {
 			operators,
 		};
 
-		Ok(Box::new(homework))
+		Ok(homework)
 	}
 
-	fn part_one(&self, homework: &dyn core::any::Any) -> Option<String> {
-		let homework = homework.downcast_ref::<Homework>()?;
-		Some(homework.solve().to_string())
+	fn part_one(&self, homework: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+		Ok(Some(homework.solve()))
 	}
 
-	fn part_two(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
-		None
+	fn part_two(&self, _intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+		Ok(None)
 	}
 }
 
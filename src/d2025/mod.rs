@@ -1,14 +0,0 @@
-crate::gen_gather_matching_solvers!(2025,
-	day01 (1) => day01::solver(),
-	day02 (2) => day02::solver(),
-	day03 (3) => day03::solver(),
-	day04 (4) => day04::solver(),
-	day05 (5) => day05::solver(),
-	day06 (6) => day06::solver(),
-	day07 (7) => day07::solver(),
-	day08 (8) => day08::solver(),
-	day09 (9) => day09::solver(),
-	day10 (10) => day10::solver(),
-	day11 (11) => day11::solver(),
-	day12 (12) => day12::solver(),
-);
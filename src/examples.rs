@@ -0,0 +1,53 @@
+//! Checking solvers against the example inputs found by the build script.
+
+use crate::{Part, Solver, answers::RecordedAnswers, params::Params, solver::solve_parts};
+
+// A test module per year, with a test per example, found by the build script.
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+/// Solve `input` with the solver for `year` and `day`, and check each part recorded in `answers`, which are in the
+/// format of an `.answers` file. The solver's parameters are set from `params`, one `name=value` per line.
+fn check(year: u16, day: u8, input: &str, answers: Option<&str>, params: Option<&str>) {
+	let answers: RecordedAnswers = answers
+		.unwrap_or_else(|| panic!("no answers are recorded for this {year}/{day} example"))
+		.parse()
+		.unwrap_or_else(|error| {
+			panic!("could not read the answers for this {year}/{day} example: {error}")
+		});
+	assert!(
+		!answers.is_empty(),
+		"no answers are recorded for this {year}/{day} example"
+	);
+
+	let params: Params = params.unwrap_or_default().parse().unwrap_or_else(|error| {
+		panic!("could not read the parameters for this {year}/{day} example: {error}")
+	});
+
+	let Some(mut solver) = crate::instantiate_solver(year, day) else {
+		panic!("{year}/{day} doesn't have a solver");
+	};
+	params.apply(&mut solver).unwrap_or_else(|error| {
+		panic!("could not set the parameters for this {year}/{day} example: {error}")
+	});
+	let Solver::PartSolve(mut solver) = solver else {
+		panic!("{year}/{day} doesn't have a PartSolve solver");
+	};
+
+	let intermediate = solver
+		.parse(input)
+		.unwrap_or_else(|error| panic!("could not parse the {year}/{day} example: {error}"));
+	let parts: Vec<Part> = [Part::One, Part::Two]
+		.into_iter()
+		.filter(|&part| answers.get(part).is_some())
+		.collect();
+
+	for (part, answer, _) in
+		solve_parts(solver.as_ref(), intermediate.as_ref(), &parts, &|_, _| {}).answers
+	{
+		assert_eq!(
+			answers.get(part),
+			answer.text().as_deref(),
+			"{part} of the {year}/{day} example gave {answer}"
+		);
+	}
+}
@@ -0,0 +1,305 @@
+use core::time::Duration;
+use std::{
+	collections::BTreeMap,
+	io::Write,
+	path::Path,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Answer, Part, bench::Summary, report::Record};
+
+/// Where timings are recorded unless `--history` says otherwise.
+pub const DEFAULT_HISTORY_PATH: &str = "target/aoc-timings.jsonl";
+
+/// How many of the most recent recorded runs make up a part's baseline.
+pub const BASELINE_RUNS: usize = 5;
+
+/// How much slower than its baseline (in percent) a part must be to count as a regression, unless `--threshold`
+/// says otherwise.
+pub const DEFAULT_THRESHOLD: f64 = 20.0;
+
+/// The timings of one part of one solver from one run, as stored in the history file.
+///
+/// The history file holds one entry per line as a flat JSON object:
+///
+/// ```text
+/// {"timestamp": 1733400000, "release": true, "year": 2024, "day": 5, "part": 1, "parse_ns": 2000, "part_ns": 1500}
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+	/// Seconds since the Unix epoch.
+	pub timestamp: u64,
+	/// Whether the run was an optimized build; debug and release timings are never compared.
+	pub release: bool,
+	pub year: u16,
+	pub day: u8,
+	pub part: Part,
+	pub parse_time: Duration,
+	pub part_time: Duration,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HistoryError {
+	#[error("I/O error occurred while accessing timing history: {0}")]
+	Io(#[from] std::io::Error),
+}
+
+/// A part which ran noticeably slower than it has recently.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+	pub year: u16,
+	pub day: u8,
+	pub part: Part,
+	pub current: Duration,
+	pub baseline: Duration,
+}
+
+impl core::fmt::Display for Regression {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let slowdown = (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0;
+
+		write!(
+			f,
+			"year {} day {} {}: {:.1?} vs. baseline {:.1?} (+{slowdown:.0}%)",
+			self.year, self.day, self.part, self.current, self.baseline
+		)
+	}
+}
+
+impl Entry {
+	/// Build entries for every record of a completed part, stamped with the current time.
+	pub fn from_records(records: &[Record]) -> Vec<Entry> {
+		let timestamp = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map_or(0, |since| since.as_secs());
+
+		records
+			.iter()
+			.filter(|record| {
+				record.failure.is_none() && record.answer.as_ref().is_some_and(Answer::is_answered)
+			})
+			.filter_map(|record| {
+				Some(Entry {
+					timestamp,
+					release: !cfg!(debug_assertions),
+					year: record.year,
+					day: record.day,
+					part: record.part,
+					parse_time: record.parse_time?,
+					part_time: record.part_time?,
+				})
+			})
+			.collect()
+	}
+
+	fn to_line(&self) -> String {
+		format!(
+			"{{\"timestamp\": {}, \"release\": {}, \"year\": {}, \"day\": {}, \"part\": {}, \"parse_ns\": {}, \"part_ns\": {}}}",
+			self.timestamp,
+			self.release,
+			self.year,
+			self.day,
+			self.part.number(),
+			self.parse_time.as_nanos(),
+			self.part_time.as_nanos(),
+		)
+	}
+
+	/// Parse one line of the history file, or return `None` if it isn't a valid entry.
+	fn from_line(line: &str) -> Option<Entry> {
+		let fields: BTreeMap<&str, &str> = line
+			.trim()
+			.strip_prefix('{')?
+			.strip_suffix('}')?
+			.split(',')
+			.map(|field| {
+				let (key, value) = field.split_once(':')?;
+				Some((
+					key.trim().strip_prefix('"')?.strip_suffix('"')?,
+					value.trim(),
+				))
+			})
+			.collect::<Option<_>>()?;
+
+		let nanos = |key: &str| -> Option<Duration> {
+			Some(Duration::from_nanos(fields.get(key)?.parse().ok()?))
+		};
+
+		Some(Entry {
+			timestamp: fields.get("timestamp")?.parse().ok()?,
+			release: fields.get("release")?.parse().ok()?,
+			year: fields.get("year")?.parse().ok()?,
+			day: fields.get("day")?.parse().ok()?,
+			part: match *fields.get("part")? {
+				"1" => Part::One,
+				"2" => Part::Two,
+				_ => return None,
+			},
+			parse_time: nanos("parse_ns")?,
+			part_time: nanos("part_ns")?,
+		})
+	}
+
+	fn key(&self) -> (bool, u16, u8, Part) {
+		(self.release, self.year, self.day, self.part)
+	}
+}
+
+/// Load all entries recorded at `path`. A missing file means nothing has been recorded yet.
+///
+/// Lines which can't be parsed (e.g. one left half-written by an interrupted run) are ignored.
+pub fn load(path: &Path) -> Result<Vec<Entry>, HistoryError> {
+	match std::fs::read_to_string(path) {
+		Ok(contents) => Ok(contents.lines().filter_map(Entry::from_line).collect()),
+		Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+		Err(error) => Err(error.into()),
+	}
+}
+
+/// Append `entries` to the history at `path`, creating it (and its directory) if needed.
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), HistoryError> {
+	if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+		std::fs::create_dir_all(dir)?;
+	}
+
+	let mut file = std::fs::OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(path)?;
+
+	let lines: String = entries.iter().map(|entry| entry.to_line() + "\n").collect();
+
+	// Written in one go so concurrent runs don't interleave partial lines.
+	file.write_all(lines.as_bytes())?;
+
+	Ok(())
+}
+
+/// Find the `current` entries whose part time exceeds the median of the last `BASELINE_RUNS` matching entries in
+/// `history` by more than `threshold` percent.
+///
+/// Entries with no history to compare against are never regressions.
+pub fn find_regressions(history: &[Entry], current: &[Entry], threshold: f64) -> Vec<Regression> {
+	let mut recent: BTreeMap<(bool, u16, u8, Part), Vec<Duration>> = BTreeMap::new();
+
+	for entry in history {
+		recent.entry(entry.key()).or_default().push(entry.part_time);
+	}
+
+	current
+		.iter()
+		.filter_map(|entry| {
+			let samples = recent.get(&entry.key())?;
+			let baseline =
+				Summary::from_samples(&samples[samples.len().saturating_sub(BASELINE_RUNS)..])?.median;
+
+			(entry.part_time.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold / 100.0))
+				.then_some(Regression {
+					year: entry.year,
+					day: entry.day,
+					part: entry.part,
+					current: entry.part_time,
+					baseline,
+				})
+		})
+		.collect()
+}
+
+/// Print the regressions found by a `--compare` run.
+pub fn print_regressions(
+	regressions: &[Regression],
+	threshold: f64,
+	out: &mut dyn Write,
+) -> std::io::Result<()> {
+	if regressions.is_empty() {
+		return writeln!(
+			out,
+			"No timing regressions (more than {threshold}% slower than the median of the last {BASELINE_RUNS} runs)."
+		);
+	}
+
+	writeln!(
+		out,
+		"{} timing regression(s) (more than {threshold}% slower than the median of the last {BASELINE_RUNS} runs):",
+		regressions.len()
+	)?;
+
+	for regression in regressions {
+		writeln!(out, "  {regression}")?;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+
+	use super::{BASELINE_RUNS, Entry, Regression, find_regressions};
+	use crate::Part;
+
+	fn entry(part: Part, part_ms: u64) -> Entry {
+		Entry {
+			timestamp: 1_733_400_000,
+			release: true,
+			year: 2024,
+			day: 5,
+			part,
+			parse_time: Duration::from_micros(2),
+			part_time: Duration::from_millis(part_ms),
+		}
+	}
+
+	#[test]
+	fn line_round_trip() {
+		let entry = entry(Part::Two, 3);
+
+		assert_eq!(
+			"{\"timestamp\": 1733400000, \"release\": true, \"year\": 2024, \"day\": 5, \"part\": 2, \"parse_ns\": 2000, \"part_ns\": 3000000}",
+			entry.to_line()
+		);
+		assert_eq!(Some(entry.clone()), Entry::from_line(&entry.to_line()));
+	}
+
+	#[test]
+	fn malformed_lines_are_rejected() {
+		for line in [
+			"",
+			"{\"timestamp\": 1, \"release\": true, \"year\": 2024, \"day\": 5, \"part\": 2, \"parse_ns\": 2000",
+			"{\"timestamp\": 1, \"release\": true, \"year\": 2024, \"day\": 5, \"part\": 3, \"parse_ns\": 1, \"part_ns\": 1}",
+		] {
+			assert_eq!(None, Entry::from_line(line));
+		}
+	}
+
+	#[test]
+	fn regression_against_recent_median() {
+		// Older runs fall outside the baseline window.
+		let mut history = vec![entry(Part::One, 1); 10];
+		history.extend([10, 10, 11, 12, 10].map(|ms| entry(Part::One, ms)));
+		assert_eq!(10 + BASELINE_RUNS, history.len());
+
+		assert!(find_regressions(&history, &[entry(Part::One, 11)], 20.0).is_empty());
+		assert_eq!(
+			vec![Regression {
+				year: 2024,
+				day: 5,
+				part: Part::One,
+				current: Duration::from_millis(13),
+				baseline: Duration::from_millis(10),
+			}],
+			find_regressions(&history, &[entry(Part::One, 13)], 20.0)
+		);
+	}
+
+	#[test]
+	fn no_regression_without_matching_history() {
+		let history = vec![entry(Part::One, 1)];
+		let debug = Entry {
+			release: false,
+			..entry(Part::One, 100)
+		};
+
+		assert!(find_regressions(&history, &[entry(Part::Two, 100), debug], 20.0).is_empty());
+	}
+}
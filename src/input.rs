@@ -0,0 +1,168 @@
+use std::{io::Read, path::PathBuf};
+
+/// Where a solver's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+	/// A file on disk.
+	File(PathBuf),
+	/// Standard input, given as `-` on the command line.
+	Stdin,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum InputFindError {
+	#[error("I/O error occurred while finding input file: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("missing \"inputs\" directory")]
+	MissingInputDir { cwd: PathBuf },
+	#[error("missing input file (tried: {tried_paths:?})")]
+	MissingInputFile { tried_paths: Vec<PathBuf> },
+	#[error("--input {input} was given, but no solvers were selected; select a single year and day")]
+	OverrideWithoutSolver { input: InputSource },
+	#[error(
+		"--input {input} is ambiguous: {} solvers were selected ({}); select a single year and day",
+		selected.len(),
+		selected.iter().map(|(year, day)| format!("{year}/{day}")).collect::<Vec<_>>().join(", ")
+	)]
+	AmbiguousOverride {
+		input: InputSource,
+		selected: Vec<(u16, u8)>,
+	},
+}
+
+impl core::str::FromStr for InputSource {
+	type Err = core::convert::Infallible;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"-" => Ok(Self::Stdin),
+			path => Ok(Self::File(PathBuf::from(path))),
+		}
+	}
+}
+
+impl core::fmt::Display for InputSource {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::File(path) => write!(f, "{}", path.display()),
+			Self::Stdin => write!(f, "-"),
+		}
+	}
+}
+
+impl InputSource {
+	/// Determine the input for the solver for `year` and `day`, preferring `input_override` if one was given.
+	pub fn locate(
+		year: u16,
+		day: u8,
+		input_override: Option<&InputSource>,
+	) -> Result<Self, InputFindError> {
+		match input_override {
+			Some(source) => Ok(source.clone()),
+			None => find_input_file(year, day).map(Self::File),
+		}
+	}
+
+	/// Check that an input override, if any, applies to exactly one selected solver.
+	pub fn check_override(
+		input_override: Option<&InputSource>,
+		selected: &[(u16, u8)],
+	) -> Result<(), InputFindError> {
+		match (input_override, selected) {
+			(None, _) | (Some(_), [_]) => Ok(()),
+			(Some(input), []) => Err(InputFindError::OverrideWithoutSolver {
+				input: input.clone(),
+			}),
+			(Some(input), selected) => Err(InputFindError::AmbiguousOverride {
+				input: input.clone(),
+				selected: selected.to_vec(),
+			}),
+		}
+	}
+
+	pub fn read(&self) -> std::io::Result<String> {
+		match self {
+			Self::File(path) => std::fs::read_to_string(path),
+			Self::Stdin => {
+				let mut data = String::new();
+				std::io::stdin().read_to_string(&mut data)?;
+				Ok(data)
+			}
+		}
+	}
+
+	/// The file path of this input, if it is one.
+	pub fn path(&self) -> Option<&std::path::Path> {
+		match self {
+			Self::File(path) => Some(path),
+			Self::Stdin => None,
+		}
+	}
+}
+
+fn find_input_file(year: u16, day: u8) -> Result<PathBuf, InputFindError> {
+	// Use relative paths directly without getting current directory
+	let inputs_root = PathBuf::from("inputs");
+	if !inputs_root.is_dir() {
+		return Err(InputFindError::MissingInputDir {
+			cwd: std::env::current_dir()?,
+		});
+	}
+
+	// If the inputs root exists, we expect to find inputs from it under one of two subtrees:
+	//
+	// - inputs/archive/{year}/day{day:02}.txt (the "archive")
+	// - inputs/{year}/day{day:02}.txt (the "current" inputs)
+	let archive_dir = inputs_root.join("archive");
+
+	let paths_to_try = [archive_dir, inputs_root]
+		.into_iter()
+		.filter(|d| d.is_dir())
+		.map(|dir| dir.join(format!("{year}/day{day:02}.txt")));
+
+	let mut tried_paths = Vec::new();
+
+	for path in paths_to_try {
+		tried_paths.push(path.clone());
+
+		if path.is_file() {
+			return Ok(path);
+		}
+	}
+
+	Err(InputFindError::MissingInputFile { tried_paths })
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::PathBuf;
+
+	use super::{InputFindError, InputSource};
+
+	#[test]
+	fn parse_source() {
+		assert_eq!(Ok(InputSource::Stdin), "-".parse());
+		assert_eq!(
+			Ok(InputSource::File(PathBuf::from("edge-case.txt"))),
+			"edge-case.txt".parse()
+		);
+	}
+
+	#[test]
+	fn override_applies_to_exactly_one_solver() {
+		let source = InputSource::Stdin;
+
+		assert!(InputSource::check_override(None, &[(2024, 1), (2024, 2)]).is_ok());
+		assert!(InputSource::check_override(Some(&source), &[(2024, 1)]).is_ok());
+		assert!(matches!(
+			InputSource::check_override(Some(&source), &[]),
+			Err(InputFindError::OverrideWithoutSolver { .. })
+		));
+
+		let error = InputSource::check_override(Some(&source), &[(2024, 1), (2024, 2)]).unwrap_err();
+		assert_eq!(
+			"--input - is ambiguous: 2 solvers were selected (2024/1, 2024/2); select a single year and day",
+			error.to_string()
+		);
+	}
+}
@@ -0,0 +1,215 @@
+//! Solvers for [Advent of Code](https://adventofcode.com) puzzles, along with the machinery to select, run and report
+//! on them. The `aoc` binary is a thin command-line interface over this library.
+//!
+//! ```no_run
+//! use aoc::{RunConstraints, Solver, gather_matching_solvers};
+//!
+//! for (year, day, solver) in gather_matching_solvers(&RunConstraints::exactly(2024, 1)) {
+//!     if let Solver::PartSolve(mut solver) = solver {
+//!         let intermediate = solver.parse(&std::fs::read_to_string("inputs/2024/day01.txt")?)?;
+//!         println!("{year} day {day}: {:?}", solver.part_one(intermediate.as_ref()));
+//!     }
+//! }
+//! # Ok::<(), Box<dyn core::error::Error>>(())
+//! ```
+
+use core::error::Error;
+
+pub mod answers;
+pub mod bench;
+pub mod constraints;
+pub use constraints::RunConstraints;
+mod dtemplate;
+#[cfg(test)]
+mod examples;
+pub mod history;
+pub mod input;
+pub mod list;
+
+pub mod options;
+pub mod params;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub use solver::{Answer, BothAnswers, Concurrent, Part, PartSolve, TypedPartSolve};
+pub mod summary;
+pub mod util;
+
+// Years -> Days -> Solvers
+
+/// Types of solvers
+pub enum Solver {
+	/// A solver that is a function that takes a string and prints its results.
+	Original(fn(&str) -> Result<(), Box<dyn Error>>),
+	/// A solver that holds a struct implementing `PartSolve`.
+	PartSolve(Box<dyn PartSolve>),
+}
+
+/// The kind of a `Solver`, without the solver itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolverKind {
+	Original,
+	PartSolve,
+}
+
+impl Solver {
+	pub fn kind(&self) -> SolverKind {
+		match self {
+			Self::Original(_) => SolverKind::Original,
+			Self::PartSolve(_) => SolverKind::PartSolve,
+		}
+	}
+}
+
+/// Creates a fresh instance of a registered solver.
+type MakeSolver = fn() -> Solver;
+
+// The year modules and the `SOLVERS`, `UNREGISTERED_DAYS` and `EXAMPLE_COUNTS` tables, found by the build script.
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
+
+/// Gather all solvers matching the provided constraints.
+#[must_use]
+pub fn gather_matching_solvers(constraints: &RunConstraints) -> Vec<(u16, u8, Solver)> {
+	SOLVERS
+		.iter()
+		.filter(|&&(year, day, _)| constraints.allows(year, day))
+		.map(|&(year, day, make_solver)| (year, day, make_solver()))
+		.collect()
+}
+
+/// Find the days matching `constraints` whose modules exist but don't export a solver.
+#[must_use]
+pub fn gather_unregistered_days(constraints: &RunConstraints) -> Vec<(u16, u8)> {
+	UNREGISTERED_DAYS
+		.iter()
+		.copied()
+		.filter(|&(year, day)| constraints.allows(year, day))
+		.collect()
+}
+
+/// The number of non-empty example inputs found for `year` and `day`; each is tested against its solver.
+#[must_use]
+pub fn count_examples(year: u16, day: u8) -> usize {
+	EXAMPLE_COUNTS
+		.iter()
+		.find(|&&(example_year, example_day, _)| (example_year, example_day) == (year, day))
+		.map_or(0, |&(_, _, count)| count)
+}
+
+pub fn instantiate_solver(year: u16, day: u8) -> Option<Solver> {
+	SOLVERS
+		.iter()
+		.find(|&&(solver_year, solver_day, _)| (solver_year, solver_day) == (year, day))
+		.map(|&(_, _, make_solver)| make_solver())
+}
+
+#[macro_export]
+macro_rules! export_solver {
+	($fn_name:ident, $make_solver_expr:expr) => {
+		pub(crate) fn $fn_name() -> $crate::Solver {
+			$make_solver_expr
+		}
+	};
+}
+
+/// Generate a test function for a part function of a solver.
+///
+/// # Examples
+///
+/// There are several variations of this macro to help easily build out the tests as information becomes available.
+///
+/// ## No input, no expected output
+///
+/// ```
+/// use aoc::{Answer, PartSolve, Solver, export_solver, part_test};
+///
+/// #[derive(Default)]
+/// struct Solution;
+///
+/// impl PartSolve for Solution {
+///     fn parse(&mut self, _input: &str) -> anyhow::Result<Box<dyn core::any::Any>> {
+///         Ok(Box::new(()))
+///     }
+///
+///     fn part_one(&self, _intermediate: &dyn core::any::Any) -> Answer {
+///         Answer::NotImplemented
+///     }
+///
+///     fn part_two(&self, _intermediate: &dyn core::any::Any) -> Answer {
+///         Answer::NotImplemented
+///     }
+/// }
+///
+/// export_solver!(solver, Solver::PartSolve(Box::new(Solution)));
+///
+/// part_test!(part_one_no_input_no_output, Solution, None, part_one, None);
+/// //         ^--+----------------------^  ^---+--^  ^--^  ^--+---^  ^--^
+/// //            |                             |      ^^      |       ^^
+/// //            |                             |  (no input)  |     (expect Answer::NotImplemented)
+/// //            |                             |              |
+/// //            |                             |              +-- fn to call on the solver after parsing completed
+/// //            |                             |
+/// //            |                             +-- Expr describing how to generate the "Solution" struct.
+/// //            |
+/// //            +-- Name of the test fn to create.
+///
+/// part_test!(part_two, Solution, None, part_two, None);
+/// ```
+#[macro_export]
+macro_rules! part_test {
+	($test_fn_name:ident, $make_solver_expr:expr, file $input_fname:literal, $part_fn_name:ident, None) => {
+		$crate::part_test! {
+			$test_fn_name,
+			let mut solver: Solution = $make_solver_expr,
+			let input: &str = include_str!($input_fname),
+			let intermediate: Box<dyn core::any::Any> = $crate::PartSolve::parse(&mut solver, input).unwrap(),
+			let part_result = $crate::PartSolve::$part_fn_name(&solver, intermediate.as_ref()),
+			assert_eq!($crate::Answer::NotImplemented, part_result)
+		}
+	};
+
+	($test_fn_name:ident, $make_solver_expr:expr, None, $part_fn_name:ident, None) => {
+		$crate::part_test! {
+			$test_fn_name,
+			let mut solver: Solution = $make_solver_expr,
+			let input: &str = "",
+			let intermediate: Box<dyn core::any::Any> = $crate::PartSolve::parse(&mut solver, input).unwrap(),
+			let part_result = $crate::PartSolve::$part_fn_name(&solver, intermediate.as_ref()),
+			assert_eq!($crate::Answer::NotImplemented, part_result)
+		}
+	};
+
+	($test_fn_name:ident, $make_solver_expr:expr, file $input_fname:literal, $part_fn_name:ident, literal $expected_output:literal) => {
+		$crate::part_test! {
+			$test_fn_name,
+			let mut solver: Solution = $make_solver_expr,
+			let input: &str = include_str!($input_fname),
+			let intermediate: Box<dyn core::any::Any> = $crate::PartSolve::parse(&mut solver, input).unwrap(),
+			let part_result = $crate::PartSolve::$part_fn_name(&solver, intermediate.as_ref()),
+			assert_eq!(Some($expected_output.to_string()), part_result.text())
+		}
+	};
+
+	($test_fn_name:ident, $make_solver_expr:expr, file $input_fname:literal, $part_fn_name:ident, file $expected_output_file:literal) => {
+		$crate::part_test! {
+			$test_fn_name,
+			let mut solver: Solution = $make_solver_expr,
+			let input: &str = include_str!($input_fname),
+			let intermediate: Box<dyn core::any::Any> = $crate::PartSolve::parse(&mut solver, input).unwrap(),
+			let part_result = $crate::PartSolve::$part_fn_name(&solver, intermediate.as_ref()),
+			assert_eq!(Some(include_str!($expected_output_file).trim().to_string()), part_result.text())
+		}
+	};
+
+	($test_fn_name:ident, $make_solver:stmt, $load_input:stmt, $parse_input:stmt, $call_solver_part_fn:stmt, $assert_expected_output:stmt) => {
+		#[test]
+		fn $test_fn_name() {
+			$make_solver
+			$load_input
+			$parse_input
+			$call_solver_part_fn
+			$assert_expected_output
+		}
+	}
+}
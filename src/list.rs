@@ -0,0 +1,186 @@
+use core::time::Duration;
+use std::io::Write;
+
+use crate::{
+	Part, RunConstraints, SolverKind,
+	answers::RecordedAnswers,
+	count_examples, gather_matching_solvers, gather_unregistered_days,
+	input::InputSource,
+	params::Params,
+	runner::{self, SolverOutcome},
+};
+
+/// Whether a part produced an answer when it was tried.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Probe {
+	Answered,
+	NoAnswer,
+	/// The part couldn't be tried: there's no input, or the solver prints its answers itself.
+	Unknown,
+	/// The solver did not run to completion.
+	Failed(&'static str),
+}
+
+impl core::fmt::Display for Probe {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Answered => write!(f, "yes"),
+			Self::NoAnswer => write!(f, "no"),
+			Self::Unknown => write!(f, "-"),
+			Self::Failed(status) => write!(f, "{status}"),
+		}
+	}
+}
+
+/// How long each solver may run when probing, unless `--timeout` says otherwise.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One row of the `list` table.
+#[derive(Debug, PartialEq)]
+pub enum Listing {
+	Registered {
+		kind: SolverKind,
+		input_found: bool,
+		/// Whether answers are recorded for the input.
+		answers_recorded: bool,
+		/// The number of example inputs the solver is tested against.
+		examples: usize,
+		/// Whether each part answered, if the solver was probed by running it.
+		probed: Option<(Probe, Probe)>,
+	},
+	/// The day has a module, but it doesn't export a solver.
+	Unregistered,
+}
+
+/// Describe a single registered solver. If `probe` is set, the solver is run on its input (if any), for at most that
+/// long, to see which parts answer.
+fn describe(year: u16, day: u8, kind: SolverKind, probe: Option<Duration>) -> Listing {
+	let input = InputSource::locate(year, day, None).ok();
+	let answers_recorded = input
+		.as_ref()
+		.and_then(InputSource::path)
+		.and_then(|path| RecordedAnswers::load(&RecordedAnswers::path_for(path)).ok())
+		.is_some_and(|recorded| !recorded.is_empty());
+
+	Listing::Registered {
+		kind,
+		input_found: input.is_some(),
+		answers_recorded,
+		examples: count_examples(year, day),
+		probed: probe.map(|timeout| probe_parts(year, day, kind, input.as_ref(), timeout)),
+	}
+}
+
+/// Run a solver on its `input` to see which parts answer.
+fn probe_parts(
+	year: u16,
+	day: u8,
+	kind: SolverKind,
+	input: Option<&InputSource>,
+	timeout: Duration,
+) -> (Probe, Probe) {
+	let Some(data) = input.and_then(|input| input.read().ok()) else {
+		return (Probe::Unknown, Probe::Unknown);
+	};
+	if kind == SolverKind::Original {
+		return (Probe::Unknown, Probe::Unknown);
+	}
+
+	let outcome = runner::run_isolated(
+		year,
+		day,
+		data,
+		&Part::ALL,
+		&Params::default(),
+		Some(timeout),
+	);
+	let probe = |part| match (&outcome, outcome.answer(part)) {
+		(_, Some(answer)) if answer.is_answered() => Probe::Answered,
+		(SolverOutcome::Failed { failure, .. }, None) => Probe::Failed(failure.status()),
+		(SolverOutcome::Uncaptured, _) => Probe::Unknown,
+		_ => Probe::NoAnswer,
+	};
+
+	(probe(Part::One), probe(Part::Two))
+}
+
+/// Describe every solver matching `constraints` (or every solver, if there are none), along with the days whose
+/// modules don't export a solver, in order of year and day.
+///
+/// If `probe` is set, each solver is run, for at most that long, to see which parts answer. Some solvers print as they
+/// go, so nothing may hold the stdout lock while they do.
+pub fn listings(constraints: &RunConstraints, probe: Option<Duration>) -> Vec<(u16, u8, Listing)> {
+	let mut rows: Vec<(u16, u8, Listing)> = gather_matching_solvers(constraints)
+		.into_iter()
+		.map(|(year, day, solver)| (year, day, describe(year, day, solver.kind(), probe)))
+		.chain(
+			gather_unregistered_days(constraints)
+				.into_iter()
+				.map(|(year, day)| (year, day, Listing::Unregistered)),
+		)
+		.collect();
+	rows.sort_by_key(|&(year, day, _)| (year, day));
+
+	rows
+}
+
+/// Print `rows`, as gathered by [`listings`], as a table. The columns saying which parts answer are only shown if
+/// the solvers were probed.
+pub fn print_listing(rows: &[(u16, u8, Listing)], out: &mut dyn Write) -> std::io::Result<()> {
+	let probed = rows.iter().any(|(_, _, listing)| {
+		matches!(
+			listing,
+			Listing::Registered {
+				probed: Some(_),
+				..
+			}
+		)
+	});
+
+	write!(
+		out,
+		"{:<4} {:>3}  {:<10} {:<5} {:<7} {:>8}",
+		"year", "day", "kind", "input", "answers", "examples"
+	)?;
+	if probed {
+		write!(out, "  {:<9} part two", "part one")?;
+	}
+	writeln!(out)?;
+
+	let yes_no = |flag: bool| if flag { "yes" } else { "no" };
+
+	for (year, day, listing) in rows {
+		match listing {
+			Listing::Registered {
+				kind,
+				input_found,
+				answers_recorded,
+				examples,
+				probed,
+			} => {
+				write!(
+					out,
+					"{year:<4} {day:>3}  {:<10} {:<5} {:<7} {examples:>8}",
+					format!("{kind:?}"),
+					yes_no(*input_found),
+					yes_no(*answers_recorded),
+				)?;
+				if let Some((part_one, part_two)) = probed {
+					write!(out, "  {:<9} {part_two}", part_one.to_string())?;
+				}
+				writeln!(out)?;
+			}
+			Listing::Unregistered => writeln!(out, "{year:<4} {day:>3}  (no solver exported)")?,
+		}
+	}
+
+	let registered = rows
+		.iter()
+		.filter(|(_, _, listing)| matches!(listing, Listing::Registered { .. }))
+		.count();
+	writeln!(
+		out,
+		"{registered} registered solver(s), {} day(s) without a solver.",
+		rows.len() - registered
+	)
+}
@@ -0,0 +1,512 @@
+use core::time::Duration;
+use std::path::PathBuf;
+
+use crate::{
+	bench::BenchConfig, history, input::InputSource, params::Params, report::ReportFormat,
+};
+
+/// Options controlling how selected solvers are run and reported.
+///
+/// These are the `--flag`-style command-line arguments; everything else is left for `RunConstraints`.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+	pub format: ReportFormat,
+	/// If set, benchmark the selected solvers instead of running them once.
+	pub bench: Option<BenchConfig>,
+	/// If set, run independent solvers concurrently on this many threads.
+	pub jobs: Option<usize>,
+	/// If set, read the input for the (single) selected solver from here instead of the `inputs` directory.
+	pub input: Option<InputSource>,
+	/// If set, give up on any solver which takes longer than this.
+	pub timeout: Option<Duration>,
+	/// If set, compare part timings against the timing history, flagging those slower than their baseline by more
+	/// than this many percent.
+	pub compare: Option<f64>,
+	/// If set, read and record timing history here instead of `history::DEFAULT_HISTORY_PATH`.
+	pub history: Option<PathBuf>,
+	/// If set, don't record this run's timings.
+	pub no_history: bool,
+	/// Values for the selected solvers' parameters, given as `--param name=value`.
+	pub params: Params,
+	/// If set, `list` runs each solver to see which parts answer.
+	pub probe: bool,
+}
+
+/// The flags understood by `RunOptions`.
+pub const FLAGS: &[&str] = &[
+	"--format",
+	"--bench",
+	"--warmup",
+	"--jobs",
+	"--input",
+	"--timeout",
+	"--compare",
+	"--threshold",
+	"--history",
+	"--no-history",
+	"--param",
+	"--probe",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum OptionsError {
+	#[error("missing value for {flag}")]
+	MissingValue { flag: String },
+	#[error("invalid value for {flag}: {message}")]
+	InvalidValue { flag: String, message: String },
+	#[error("{flag} cannot be combined with {other}")]
+	Conflict { flag: String, other: String },
+}
+
+impl RunOptions {
+	/// Extract options from command-line arguments, returning the options along with the arguments that
+	/// were not consumed.
+	pub fn parse_from_args(
+		args: impl IntoIterator<Item = String>,
+	) -> Result<(Self, Vec<String>), OptionsError> {
+		let mut options = Self::default();
+		let mut remaining = Vec::new();
+
+		let mut bench_iterations: Option<usize> = None;
+		let mut warmup: Option<usize> = None;
+		let mut compare = false;
+		let mut threshold: Option<f64> = None;
+
+		let mut args = args.into_iter();
+
+		while let Some(arg) = args.next() {
+			// Accept both `--flag value` and `--flag=value`.
+			let (flag, inline_value) = match arg.split_once('=') {
+				Some((flag, value)) if flag.starts_with("--") => {
+					(flag.to_string(), Some(value.to_string()))
+				}
+				_ => (arg.clone(), None),
+			};
+
+			let mut value = || {
+				inline_value
+					.clone()
+					.or_else(|| args.next())
+					.ok_or_else(|| OptionsError::MissingValue { flag: flag.clone() })
+			};
+
+			match flag.as_str() {
+				"--format" => options.format = parse_value(&flag, &value()?)?,
+				"--bench" => bench_iterations = Some(parse_value(&flag, &value()?)?),
+				"--warmup" => warmup = Some(parse_value(&flag, &value()?)?),
+				"--jobs" => options.jobs = Some(parse_value(&flag, &value()?)?),
+				"--input" => options.input = Some(parse_value(&flag, &value()?)?),
+				"--timeout" => {
+					let seconds: f64 = parse_value(&flag, &value()?)?;
+					let timeout = Duration::try_from_secs_f64(seconds)
+						.ok()
+						.filter(|timeout| !timeout.is_zero())
+						.ok_or_else(|| OptionsError::InvalidValue {
+							flag: flag.clone(),
+							message: format!("{seconds} is not a positive number of seconds"),
+						})?;
+					options.timeout = Some(timeout);
+				}
+				"--compare" => compare = true,
+				"--threshold" => {
+					let percent: f64 = parse_value(&flag, &value()?)?;
+					if !(percent.is_finite() && percent >= 0.0) {
+						return Err(OptionsError::InvalidValue {
+							flag: flag.clone(),
+							message: format!("{percent} is not a non-negative percentage"),
+						});
+					}
+					threshold = Some(percent);
+				}
+				"--history" => options.history = Some(PathBuf::from(value()?)),
+				"--no-history" => options.no_history = true,
+				"--param" => {
+					options
+						.params
+						.assign(&value()?)
+						.map_err(|error| OptionsError::InvalidValue {
+							flag: flag.clone(),
+							message: error.to_string(),
+						})?;
+				}
+				"--probe" => options.probe = true,
+				_ => remaining.push(arg),
+			}
+		}
+
+		match (bench_iterations, warmup) {
+			(Some(0), _) => {
+				return Err(OptionsError::InvalidValue {
+					flag: "--bench".to_string(),
+					message: "at least one iteration is required".to_string(),
+				});
+			}
+			(Some(iterations), warmup) => {
+				options.bench = Some(BenchConfig {
+					iterations,
+					warmup: warmup.unwrap_or(BenchConfig::DEFAULT_WARMUP),
+				});
+			}
+			(None, Some(_)) => {
+				return Err(OptionsError::Conflict {
+					flag: "--warmup".to_string(),
+					other: "a run without --bench".to_string(),
+				});
+			}
+			(None, None) => {}
+		}
+
+		match (compare, threshold) {
+			(true, threshold) => {
+				options.compare = Some(threshold.unwrap_or(history::DEFAULT_THRESHOLD));
+			}
+			(false, Some(_)) => {
+				return Err(OptionsError::Conflict {
+					flag: "--threshold".to_string(),
+					other: "a run without --compare".to_string(),
+				});
+			}
+			(false, None) => {}
+		}
+
+		options.validate()?;
+
+		Ok((options, remaining))
+	}
+
+	/// Reject combinations of options which make no sense together.
+	fn validate(&self) -> Result<(), OptionsError> {
+		if self.jobs == Some(0) {
+			return Err(OptionsError::InvalidValue {
+				flag: "--jobs".to_string(),
+				message: "at least one thread is required".to_string(),
+			});
+		}
+
+		// Benchmarks are measured one solver at a time so they don't compete for cores.
+		if self.bench.is_some() && self.jobs.is_some() {
+			return Err(OptionsError::Conflict {
+				flag: "--bench".to_string(),
+				other: "--jobs".to_string(),
+			});
+		}
+
+		if self.bench.is_some() && self.format != ReportFormat::Text {
+			return Err(OptionsError::Conflict {
+				flag: "--bench".to_string(),
+				other: "--format".to_string(),
+			});
+		}
+
+		// Benchmarks keep no history, and timings on another input or with other parameters aren't comparable with it.
+		for (conflicting, other) in [
+			(self.bench.is_some(), "--bench"),
+			(self.input.is_some(), "--input"),
+			(!self.params.is_empty(), "--param"),
+		] {
+			if self.compare.is_some() && conflicting {
+				return Err(OptionsError::Conflict {
+					flag: "--compare".to_string(),
+					other: other.to_string(),
+				});
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Reject options which don't apply to a run of the selected solvers.
+	pub fn check_for_run(&self) -> Result<(), OptionsError> {
+		reject_conflicting(&[(self.probe, "--probe")], "a run")
+	}
+
+	/// Reject options which don't apply to the `list` subcommand; only `--probe` does, along with `--timeout` for the
+	/// solvers it runs.
+	pub fn check_for_list(&self) -> Result<(), OptionsError> {
+		let conflicting = [
+			(self.format != ReportFormat::Text, "--format"),
+			(self.bench.is_some(), "--bench"),
+			(self.jobs.is_some(), "--jobs"),
+			(self.input.is_some(), "--input"),
+			(self.compare.is_some(), "--compare"),
+			(!self.params.is_empty(), "--param"),
+		];
+		reject_conflicting(&conflicting, "list")?;
+
+		reject_conflicting(
+			&[(self.timeout.is_some() && !self.probe, "--timeout")],
+			"list without --probe",
+		)
+	}
+
+	/// Reject options given to the `new` subcommand, none of which apply to it.
+	pub fn check_for_new(&self) -> Result<(), OptionsError> {
+		let conflicting = [
+			(self.format != ReportFormat::Text, "--format"),
+			(self.bench.is_some(), "--bench"),
+			(self.jobs.is_some(), "--jobs"),
+			(self.input.is_some(), "--input"),
+			(self.timeout.is_some(), "--timeout"),
+			(self.compare.is_some(), "--compare"),
+			(self.history.is_some(), "--history"),
+			(self.no_history, "--no-history"),
+			(!self.params.is_empty(), "--param"),
+			(self.probe, "--probe"),
+		];
+
+		reject_conflicting(&conflicting, "new")
+	}
+
+	/// Where timing history is read from and recorded to.
+	pub fn history_path(&self) -> PathBuf {
+		self
+			.history
+			.clone()
+			.unwrap_or_else(|| PathBuf::from(history::DEFAULT_HISTORY_PATH))
+	}
+
+	/// Whether this run's timings should be recorded to the timing history.
+	///
+	/// Benchmarks report their own statistics, and timings on an overridden input or with overridden parameters
+	/// aren't comparable with the rest.
+	pub fn records_history(&self) -> bool {
+		!self.no_history && self.bench.is_none() && self.input.is_none() && self.params.is_empty()
+	}
+}
+
+/// Fail with a conflict between `other` and the first flag which was given.
+fn reject_conflicting(conflicting: &[(bool, &str)], other: &str) -> Result<(), OptionsError> {
+	match conflicting.iter().find(|(conflicting, _)| *conflicting) {
+		Some((_, flag)) => Err(OptionsError::Conflict {
+			flag: (*flag).to_string(),
+			other: other.to_string(),
+		}),
+		None => Ok(()),
+	}
+}
+
+fn parse_value<T>(flag: &str, value: &str) -> Result<T, OptionsError>
+where
+	T: core::str::FromStr,
+	T::Err: core::fmt::Display,
+{
+	value
+		.parse()
+		.map_err(|error: T::Err| OptionsError::InvalidValue {
+			flag: flag.to_string(),
+			message: error.to_string(),
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+	use std::path::PathBuf;
+
+	use super::{BenchConfig, InputSource, OptionsError, Params, ReportFormat, RunOptions, history};
+
+	fn args(args: &[&str]) -> Vec<String> {
+		args.iter().map(ToString::to_string).collect()
+	}
+
+	#[test]
+	fn format_separate_and_inline() {
+		let (options, remaining) =
+			RunOptions::parse_from_args(args(&["2024", "--format", "json", "5"])).unwrap();
+		assert_eq!(ReportFormat::Json, options.format);
+		assert_eq!(args(&["2024", "5"]), remaining);
+
+		let (options, _) = RunOptions::parse_from_args(args(&["--format=csv"])).unwrap();
+		assert_eq!(ReportFormat::Csv, options.format);
+	}
+
+	#[test]
+	fn format_errors() {
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--format"])),
+			Err(OptionsError::MissingValue { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--format", "xml"])),
+			Err(OptionsError::InvalidValue { .. })
+		));
+	}
+
+	#[test]
+	fn bench_with_default_and_explicit_warmup() {
+		let (options, remaining) =
+			RunOptions::parse_from_args(args(&["--bench", "10", "2024"])).unwrap();
+		assert_eq!(
+			Some(BenchConfig {
+				iterations: 10,
+				warmup: BenchConfig::DEFAULT_WARMUP
+			}),
+			options.bench
+		);
+		assert_eq!(args(&["2024"]), remaining);
+
+		let (options, _) = RunOptions::parse_from_args(args(&["--warmup=0", "--bench=5"])).unwrap();
+		assert_eq!(
+			Some(BenchConfig {
+				iterations: 5,
+				warmup: 0
+			}),
+			options.bench
+		);
+	}
+
+	#[test]
+	fn bench_errors() {
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--bench", "0"])),
+			Err(OptionsError::InvalidValue { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--warmup", "2"])),
+			Err(OptionsError::Conflict { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--bench", "2", "--format", "json"])),
+			Err(OptionsError::Conflict { .. })
+		));
+	}
+
+	#[test]
+	fn jobs() {
+		let (options, remaining) = RunOptions::parse_from_args(args(&["--jobs", "4", "2020"])).unwrap();
+		assert_eq!(Some(4), options.jobs);
+		assert_eq!(args(&["2020"]), remaining);
+
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--jobs", "0"])),
+			Err(OptionsError::InvalidValue { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--jobs", "2", "--bench", "3"])),
+			Err(OptionsError::Conflict { .. })
+		));
+	}
+
+	#[test]
+	fn input() {
+		let (options, remaining) =
+			RunOptions::parse_from_args(args(&["2024", "5", "--input", "-"])).unwrap();
+		assert_eq!(Some(InputSource::Stdin), options.input);
+		assert_eq!(args(&["2024", "5"]), remaining);
+	}
+
+	#[test]
+	fn timeout() {
+		let (options, _) = RunOptions::parse_from_args(args(&["--timeout", "2.5"])).unwrap();
+		assert_eq!(Some(Duration::from_millis(2500)), options.timeout);
+
+		for invalid in ["0", "-1", "soon"] {
+			assert!(matches!(
+				RunOptions::parse_from_args(args(&["--timeout", invalid])),
+				Err(OptionsError::InvalidValue { .. })
+			));
+		}
+	}
+
+	#[test]
+	fn compare_and_history() {
+		let (options, remaining) = RunOptions::parse_from_args(args(&["--compare", "2024"])).unwrap();
+		assert_eq!(Some(history::DEFAULT_THRESHOLD), options.compare);
+		assert_eq!(args(&["2024"]), remaining);
+		assert!(options.records_history());
+
+		let (options, _) = RunOptions::parse_from_args(args(&[
+			"--threshold=5",
+			"--compare",
+			"--history",
+			"timings.jsonl",
+			"--no-history",
+		]))
+		.unwrap();
+		assert_eq!(Some(5.0), options.compare);
+		assert_eq!(PathBuf::from("timings.jsonl"), options.history_path());
+		assert!(!options.records_history());
+
+		let (options, _) = RunOptions::parse_from_args(args(&["--input", "-"])).unwrap();
+		assert!(!options.records_history());
+	}
+
+	#[test]
+	fn params() {
+		let (options, remaining) =
+			RunOptions::parse_from_args(args(&["--param", "end=10", "2020", "--param=size=3x3"]))
+				.unwrap();
+		assert_eq!(
+			"end=10\nsize=3x3".parse::<Params>().unwrap(),
+			options.params
+		);
+		assert_eq!(vec!["2020".to_string()], remaining);
+		assert!(!options.records_history());
+
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--param", "end"])),
+			Err(OptionsError::InvalidValue { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--param", "end=10", "--compare"])),
+			Err(OptionsError::Conflict { .. })
+		));
+	}
+
+	#[test]
+	fn list_accepts_only_probe_and_timeout() {
+		let (options, _) =
+			RunOptions::parse_from_args(args(&["list", "--probe", "--timeout", "1"])).unwrap();
+		assert!(options.check_for_list().is_ok());
+
+		let (options, _) = RunOptions::parse_from_args(args(&["list", "--timeout", "1"])).unwrap();
+		assert!(matches!(
+			options.check_for_list(),
+			Err(OptionsError::Conflict { .. })
+		));
+
+		let (options, _) = RunOptions::parse_from_args(args(&["--probe", "2024"])).unwrap();
+		assert!(matches!(
+			options.check_for_run(),
+			Err(OptionsError::Conflict { .. })
+		));
+
+		let (options, _) = RunOptions::parse_from_args(args(&["list", "--jobs", "2"])).unwrap();
+		assert!(matches!(
+			options.check_for_list(),
+			Err(OptionsError::Conflict { .. })
+		));
+	}
+
+	#[test]
+	fn new_accepts_no_options() {
+		let (options, _) = RunOptions::parse_from_args(args(&["new", "2026", "1"])).unwrap();
+		assert!(options.check_for_new().is_ok());
+
+		let (options, _) = RunOptions::parse_from_args(args(&["new", "--timeout", "1"])).unwrap();
+		assert!(matches!(
+			options.check_for_new(),
+			Err(OptionsError::Conflict { .. })
+		));
+	}
+
+	#[test]
+	fn compare_errors() {
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--threshold", "10"])),
+			Err(OptionsError::Conflict { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--compare", "--threshold", "-3"])),
+			Err(OptionsError::InvalidValue { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--compare", "--bench", "3"])),
+			Err(OptionsError::Conflict { .. })
+		));
+		assert!(matches!(
+			RunOptions::parse_from_args(args(&["--compare", "--input", "-"])),
+			Err(OptionsError::Conflict { .. })
+		));
+	}
+}
@@ -0,0 +1,236 @@
+//! Named values which solvers read instead of hard-coding constants that differ between the examples and the real
+//! input, such as the size of a grid or the number of rounds to play.
+//!
+//! A solver declares its parameters, with their current values, through [`PartSolve::params`], and accepts new values
+//! through [`PartSolve::set_param`] before its input is parsed. A fresh solver's values are its defaults.
+
+use std::collections::BTreeMap;
+
+use crate::{PartSolve, Solver};
+
+/// A parameter a solver reads, and its current value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+	pub name: &'static str,
+	pub value: String,
+	pub description: &'static str,
+}
+
+impl Param {
+	pub fn new(name: &'static str, value: &impl ToString, description: &'static str) -> Self {
+		Self {
+			name,
+			value: value.to_string(),
+			description,
+		}
+	}
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParamError {
+	#[error("expected name=value, got {0:?}")]
+	Malformed(String),
+	#[error("unknown parameter {name} (known: {})", if known.is_empty() { "none".to_string() } else { known.join(", ") })]
+	Unknown {
+		name: String,
+		known: Vec<&'static str>,
+	},
+	#[error("invalid value {value:?} for parameter {name}: {message}")]
+	InvalidValue {
+		name: String,
+		value: String,
+		message: String,
+	},
+	#[error("only PartSolve solvers take parameters")]
+	NotPartSolve,
+}
+
+impl ParamError {
+	/// The error for a parameter which isn't among `params`.
+	pub fn unknown(name: &str, params: &[Param]) -> Self {
+		Self::Unknown {
+			name: name.to_string(),
+			known: params.iter().map(|param| param.name).collect(),
+		}
+	}
+}
+
+/// Parse the value given for the parameter `name`.
+pub fn parse_value<T>(name: &str, value: &str) -> Result<T, ParamError>
+where
+	T: core::str::FromStr,
+	T::Err: core::fmt::Display,
+{
+	value
+		.parse()
+		.map_err(|error: T::Err| ParamError::InvalidValue {
+			name: name.to_string(),
+			value: value.to_string(),
+			message: error.to_string(),
+		})
+}
+
+/// Values given for parameters, by name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Add a `name=value` assignment, replacing any earlier value for the same name.
+	pub fn assign(&mut self, assignment: &str) -> Result<(), ParamError> {
+		let Some((name, value)) = assignment
+			.split_once('=')
+			.filter(|(name, _)| !name.trim().is_empty())
+		else {
+			return Err(ParamError::Malformed(assignment.to_string()));
+		};
+
+		self
+			.0
+			.insert(name.trim().to_string(), value.trim().to_string());
+
+		Ok(())
+	}
+
+	/// Set these parameters on `solver`, each of which it must declare.
+	pub fn apply(&self, solver: &mut Solver) -> Result<(), ParamError> {
+		match solver {
+			Solver::PartSolve(solver) => self.apply_to(solver.as_mut()),
+			Solver::Original(_) if self.is_empty() => Ok(()),
+			Solver::Original(_) => Err(ParamError::NotPartSolve),
+		}
+	}
+
+	fn apply_to(&self, solver: &mut dyn PartSolve) -> Result<(), ParamError> {
+		let declared = solver.params();
+
+		for (name, value) in &self.0 {
+			if !declared.iter().any(|param| param.name == name) {
+				return Err(ParamError::unknown(name, &declared));
+			}
+
+			solver.set_param(name, value)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl core::str::FromStr for Params {
+	type Err = ParamError;
+
+	/// Parse one `name=value` assignment per line, skipping blank lines and lines starting with `#`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut params = Self::default();
+
+		for line in s.lines().map(str::trim) {
+			if !line.is_empty() && !line.starts_with('#') {
+				params.assign(line)?;
+			}
+		}
+
+		Ok(params)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Param, ParamError, Params, parse_value};
+	use crate::{Solver, TypedPartSolve};
+
+	struct Rounds(u32);
+
+	impl TypedPartSolve for Rounds {
+		type Intermediate = ();
+		type PartOne = u32;
+		type PartTwo = u32;
+
+		fn params(&self) -> Vec<Param> {
+			vec![Param::new("rounds", &self.0, "how many rounds to play")]
+		}
+
+		fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+			match name {
+				"rounds" => self.0 = parse_value(name, value)?,
+				_ => return Err(ParamError::unknown(name, &self.params())),
+			}
+
+			Ok(())
+		}
+
+		fn parse(&mut self, _input: &str) -> anyhow::Result<()> {
+			Ok(())
+		}
+
+		fn part_one(&self, _intermediate: &()) -> anyhow::Result<Option<u32>> {
+			Ok(Some(self.0))
+		}
+
+		fn part_two(&self, _intermediate: &()) -> anyhow::Result<Option<u32>> {
+			Ok(None)
+		}
+	}
+
+	#[test]
+	fn parses_assignments() {
+		let params: Params = "# a comment\nrounds = 10\n\nsize=3x3\n".parse().unwrap();
+		let mut expected = Params::default();
+		expected.assign("rounds=10").unwrap();
+		expected.assign("size=3x3").unwrap();
+
+		assert_eq!(expected, params);
+		assert!(matches!(
+			"rounds".parse::<Params>(),
+			Err(ParamError::Malformed(_))
+		));
+		assert!(matches!(
+			"=10".parse::<Params>(),
+			Err(ParamError::Malformed(_))
+		));
+	}
+
+	#[test]
+	fn applies_declared_params() {
+		let mut solver = Solver::PartSolve(Box::new(Rounds(100)));
+		"rounds=10"
+			.parse::<Params>()
+			.unwrap()
+			.apply(&mut solver)
+			.unwrap();
+
+		let Solver::PartSolve(solver) = solver else {
+			unreachable!()
+		};
+		assert_eq!(
+			vec![Param::new("rounds", &10, "how many rounds to play")],
+			solver.params()
+		);
+	}
+
+	#[test]
+	fn rejects_unknown_and_invalid_params() {
+		let mut solver = Solver::PartSolve(Box::new(Rounds(100)));
+
+		let error = "size=3"
+			.parse::<Params>()
+			.unwrap()
+			.apply(&mut solver)
+			.unwrap_err();
+		assert_eq!("unknown parameter size (known: rounds)", error.to_string());
+
+		assert!(matches!(
+			"rounds=many".parse::<Params>().unwrap().apply(&mut solver),
+			Err(ParamError::InvalidValue { .. })
+		));
+
+		let mut original = Solver::Original(|_| Ok(()));
+		assert!(Params::default().apply(&mut original).is_ok());
+		assert!(matches!(
+			"rounds=1".parse::<Params>().unwrap().apply(&mut original),
+			Err(ParamError::NotPartSolve)
+		));
+	}
+}
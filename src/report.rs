@@ -0,0 +1,337 @@
+use core::time::Duration;
+use std::io::Write;
+
+use crate::{
+	Answer, Part,
+	answers::{RecordedAnswers, Verdict},
+	runner::SolverOutcome,
+};
+
+/// How the results of a run are presented.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ReportFormat {
+	/// Free-form text, printed as each solver finishes.
+	#[default]
+	Text,
+	/// A JSON array with one object per (year, day, part).
+	Json,
+	/// CSV with a header row and one row per (year, day, part).
+	Csv,
+}
+
+impl core::str::FromStr for ReportFormat {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"text" => Ok(Self::Text),
+			"json" => Ok(Self::Json),
+			"csv" => Ok(Self::Csv),
+			other => Err(format!(
+				"unknown report format {other:?} (expected text, json or csv)"
+			)),
+		}
+	}
+}
+
+/// One reported answer: the result of a single part of a single solver.
+pub struct Record {
+	pub year: u16,
+	pub day: u8,
+	pub part: Part,
+	/// `None` if the part wasn't run, or its answer wasn't captured.
+	pub answer: Option<Answer>,
+	pub verdict: Option<Verdict>,
+	/// Set if the solver did not run to completion.
+	pub failure: Option<&'static str>,
+	/// Time spent parsing, shared by both parts; `None` if the solver's timings are unavailable.
+	pub parse_time: Option<Duration>,
+	/// Time spent solving this part; `None` if unavailable.
+	pub part_time: Option<Duration>,
+}
+
+impl Record {
+	/// Build the records for the given `parts` of a solver run.
+	pub fn from_outcome(
+		year: u16,
+		day: u8,
+		outcome: &SolverOutcome,
+		recorded: &RecordedAnswers,
+		parts: &[Part],
+	) -> Vec<Record> {
+		parts
+			.iter()
+			.map(|&part| {
+				let answer = outcome.answer(part);
+
+				Record {
+					year,
+					day,
+					part,
+					answer: answer.cloned(),
+					// A part solved before its solver failed is verified like any other; the rest share the failure.
+					verdict: match outcome {
+						SolverOutcome::Uncaptured => None,
+						SolverOutcome::Failed { .. } if answer.is_none() => None,
+						SolverOutcome::Captured { .. } | SolverOutcome::Failed { .. } => {
+							recorded.verify(part, answer.and_then(Answer::text).as_deref())
+						}
+					},
+					failure: match outcome {
+						SolverOutcome::Failed { failure, .. } if answer.is_none() => Some(failure.status()),
+						SolverOutcome::Uncaptured
+						| SolverOutcome::Captured { .. }
+						| SolverOutcome::Failed { .. } => None,
+					},
+					parse_time: outcome.timings().map(|timings| timings.parse),
+					part_time: outcome.timings().and_then(|timings| timings.part(part)),
+				}
+			})
+			.collect()
+	}
+
+	/// The answer as it is verified and reported; see [`Answer::text`].
+	fn answer_text(&self) -> Option<String> {
+		self.answer.as_ref().and_then(Answer::text)
+	}
+
+	fn status(&self) -> Option<&'static str> {
+		self.failure.or_else(|| {
+			self.verdict.as_ref().map(|verdict| match verdict {
+				Verdict::Pass => "pass",
+				Verdict::Fail { .. } => "fail",
+				Verdict::New => "new",
+			})
+		})
+	}
+}
+
+/// Print the answers of a solver run as text, checking each against the answers recorded for its input.
+pub fn print_outcome(
+	outcome: &SolverOutcome,
+	recorded: &RecordedAnswers,
+	parts: &[Part],
+	out: &mut dyn Write,
+) -> std::io::Result<()> {
+	if let SolverOutcome::Uncaptured = outcome {
+		if !recorded.is_empty() {
+			writeln!(out, "  (answers printed by solver; not verified)")?;
+		}
+
+		return Ok(());
+	}
+
+	for &part in parts {
+		let Some(answer) = outcome.answer(part) else {
+			continue;
+		};
+
+		match recorded.verify(part, answer.text().as_deref()) {
+			Some(verdict) => writeln!(out, "{part}: {answer} [{verdict}]")?,
+			// Without a recorded answer, a missing answer is only worth mentioning if the part failed.
+			None if matches!(answer, Answer::Error(_)) => writeln!(out, "{part}: {answer}")?,
+			None => {}
+		}
+
+		// Show the picture a grid answer was read from, so a misread can be spotted.
+		if let Answer::Grid(grid) = answer
+			&& crate::util::ocr::read(grid).is_some()
+		{
+			for line in grid.lines() {
+				writeln!(out, "    {line}")?;
+			}
+		}
+	}
+
+	let timings = match outcome {
+		SolverOutcome::Captured { timings, .. } => timings,
+		SolverOutcome::Uncaptured => return Ok(()),
+		// Any parts solved before the failure have been printed above.
+		SolverOutcome::Failed { failure, .. } => return writeln!(out, "  ({failure})"),
+	};
+
+	writeln!(
+		out,
+		"  (timings: {{parse: {:.1?}, part_one: {}, part_two: {}, total: {:.1?}}}{})",
+		timings.parse,
+		timings
+			.part_one
+			.map_or_else(|| "n/a".to_string(), |dur| format!("{dur:.1?}")),
+		timings
+			.part_two
+			.map_or_else(|| "n/a".to_string(), |dur| format!("{dur:.1?}")),
+		timings.total,
+		if timings.concurrent {
+			"; parts ran concurrently"
+		} else {
+			""
+		},
+	)
+}
+
+/// Write `records` as a JSON array, one object per line.
+///
+/// Durations are reported in integer nanoseconds; unavailable values are `null`.
+pub fn write_json(records: &[Record], out: &mut impl Write) -> std::io::Result<()> {
+	fn string_or_null(value: Option<&str>) -> String {
+		value.map_or_else(|| "null".to_string(), json_string)
+	}
+
+	fn nanos_or_null(value: Option<Duration>) -> String {
+		value.map_or_else(|| "null".to_string(), |dur| dur.as_nanos().to_string())
+	}
+
+	writeln!(out, "[")?;
+
+	for (idx, record) in records.iter().enumerate() {
+		let separator = if idx + 1 < records.len() { "," } else { "" };
+
+		writeln!(
+			out,
+			"  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"kind\": {}, \"status\": {}, \"parse_ns\": {}, \"part_ns\": {}}}{separator}",
+			record.year,
+			record.day,
+			record.part.number(),
+			string_or_null(record.answer_text().as_deref()),
+			string_or_null(record.answer.as_ref().map(Answer::kind)),
+			string_or_null(record.status()),
+			nanos_or_null(record.parse_time),
+			nanos_or_null(record.part_time),
+		)?;
+	}
+
+	writeln!(out, "]")
+}
+
+/// Write `records` as CSV with a header row.
+///
+/// Durations are reported in integer nanoseconds; unavailable values are left empty.
+pub fn write_csv(records: &[Record], out: &mut impl Write) -> std::io::Result<()> {
+	fn nanos_or_empty(value: Option<Duration>) -> String {
+		value
+			.map(|dur| dur.as_nanos().to_string())
+			.unwrap_or_default()
+	}
+
+	writeln!(out, "year,day,part,answer,kind,status,parse_ns,part_ns")?;
+
+	for record in records {
+		writeln!(
+			out,
+			"{},{},{},{},{},{},{},{}",
+			record.year,
+			record.day,
+			record.part.number(),
+			record
+				.answer_text()
+				.as_deref()
+				.map(csv_field)
+				.unwrap_or_default(),
+			record.answer.as_ref().map(Answer::kind).unwrap_or_default(),
+			record.status().unwrap_or_default(),
+			nanos_or_empty(record.parse_time),
+			nanos_or_empty(record.part_time),
+		)?;
+	}
+
+	Ok(())
+}
+
+fn json_string(value: &str) -> String {
+	use core::fmt::Write as _;
+
+	let mut escaped = String::with_capacity(value.len() + 2);
+
+	escaped.push('"');
+	for c in value.chars() {
+		match c {
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => escaped.push_str("\\r"),
+			'\t' => escaped.push_str("\\t"),
+			c if c.is_control() => {
+				// Writing to a String cannot fail.
+				let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+			}
+			c => escaped.push(c),
+		}
+	}
+	escaped.push('"');
+
+	escaped
+}
+
+fn csv_field(value: &str) -> String {
+	if value.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", value.replace('"', "\"\""))
+	} else {
+		value.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+
+	use super::{Record, Verdict, csv_field, json_string, write_csv, write_json};
+	use crate::{Answer, Part};
+
+	fn records() -> Vec<Record> {
+		vec![
+			Record {
+				year: 2024,
+				day: 5,
+				part: Part::One,
+				answer: Some(Answer::Integer(143)),
+				verdict: Some(Verdict::Pass),
+				failure: None,
+				parse_time: Some(Duration::from_micros(2)),
+				part_time: Some(Duration::from_nanos(1500)),
+			},
+			Record {
+				year: 2015,
+				day: 1,
+				part: Part::Two,
+				answer: None,
+				verdict: None,
+				failure: Some("panicked"),
+				parse_time: None,
+				part_time: None,
+			},
+		]
+	}
+
+	#[test]
+	fn json_escapes() {
+		assert_eq!(r#""a\"b\\c\nd""#, json_string("a\"b\\c\nd"));
+	}
+
+	#[test]
+	fn csv_quotes() {
+		assert_eq!("plain", csv_field("plain"));
+		assert_eq!("\"a,\"\"b\"\"\"", csv_field("a,\"b\""));
+	}
+
+	#[test]
+	fn json_report() {
+		let mut out = Vec::new();
+		write_json(&records(), &mut out).unwrap();
+
+		assert_eq!(
+			"[\n  {\"year\": 2024, \"day\": 5, \"part\": 1, \"answer\": \"143\", \"kind\": \"integer\", \"status\": \"pass\", \"parse_ns\": 2000, \"part_ns\": 1500},\n  {\"year\": 2015, \"day\": 1, \"part\": 2, \"answer\": null, \"kind\": null, \"status\": \"panicked\", \"parse_ns\": null, \"part_ns\": null}\n]\n",
+			String::from_utf8(out).unwrap()
+		);
+	}
+
+	#[test]
+	fn csv_report() {
+		let mut out = Vec::new();
+		write_csv(&records(), &mut out).unwrap();
+
+		assert_eq!(
+			"year,day,part,answer,kind,status,parse_ns,part_ns\n2024,5,1,143,integer,pass,2000,1500\n2015,1,2,,,panicked,,\n",
+			String::from_utf8(out).unwrap()
+		);
+	}
+}
@@ -0,0 +1,821 @@
+use core::{any::Any, error::Error, time::Duration};
+use std::{
+	io::Write,
+	sync::mpsc::{self, RecvTimeoutError},
+	time::Instant,
+};
+
+use rayon::prelude::*;
+
+use crate::{
+	Answer, Part, Solver, SolverKind,
+	answers::{RecordedAnswers, Verdict},
+	bench::{self, BenchConfig, PhaseSamples},
+	input::InputSource,
+	instantiate_solver,
+	options::RunOptions,
+	params::Params,
+	report::{self, Record, ReportFormat},
+	solver::{OnSolved, solve_parts},
+	summary::{self, SolverResult},
+};
+
+/// Per-phase timings for a `PartSolve`-class solver run.
+pub struct Timings {
+	pub parse: Duration,
+	/// `None` if part one wasn't answered.
+	pub part_one: Option<Duration>,
+	/// `None` if part two wasn't answered.
+	pub part_two: Option<Duration>,
+	/// Wall time spent parsing and solving every selected part, answered or not.
+	pub total: Duration,
+	/// Whether the parts were solved at the same time, in which case their times add up to more than `total`.
+	pub concurrent: bool,
+}
+
+impl Timings {
+	pub fn part(&self, part: Part) -> Option<Duration> {
+		match part {
+			Part::One => self.part_one,
+			Part::Two => self.part_two,
+		}
+	}
+}
+
+/// What running a single solver produced.
+pub enum SolverOutcome {
+	/// "Original"-class solvers print their own output, so their answers and timings are not captured.
+	Uncaptured,
+	/// "PartSolve"-class solvers hand their answers back to the runner.
+	Captured {
+		/// `None` if the part was not selected.
+		part_one: Option<Answer>,
+		part_two: Option<Answer>,
+		timings: Timings,
+	},
+	/// The solver did not run to completion.
+	Failed {
+		failure: Failure,
+		/// Answers to the parts solved before the solver failed; `None` if the part was not selected or solved.
+		part_one: Option<Answer>,
+		part_two: Option<Answer>,
+	},
+}
+
+/// Ways in which a solver can fail to run to completion.
+pub enum Failure {
+	/// The solver returned an error.
+	Error(String),
+	/// The solver panicked.
+	Panic(String),
+	/// The solver was abandoned after running for too long.
+	Timeout(Duration),
+}
+
+impl core::fmt::Display for Failure {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Error(message) => write!(f, "failed: {message}"),
+			Self::Panic(message) => write!(f, "panicked: {message}"),
+			Self::Timeout(after) => write!(f, "timed out after {after:.1?}"),
+		}
+	}
+}
+
+impl Failure {
+	/// A short, machine-readable name for this kind of failure.
+	pub fn status(&self) -> &'static str {
+		match self {
+			Self::Error(_) => "errored",
+			Self::Panic(_) => "panicked",
+			Self::Timeout(_) => "timed_out",
+		}
+	}
+}
+
+impl SolverOutcome {
+	/// A failure before any part was solved.
+	pub fn failed(failure: Failure) -> Self {
+		Self::Failed {
+			failure,
+			part_one: None,
+			part_two: None,
+		}
+	}
+
+	pub fn answer(&self, part: Part) -> Option<&Answer> {
+		match (self, part) {
+			(Self::Uncaptured, _) => None,
+			(Self::Captured { part_one, .. } | Self::Failed { part_one, .. }, Part::One) => {
+				part_one.as_ref()
+			}
+			(Self::Captured { part_two, .. } | Self::Failed { part_two, .. }, Part::Two) => {
+				part_two.as_ref()
+			}
+		}
+	}
+
+	pub fn timings(&self) -> Option<&Timings> {
+		match self {
+			Self::Uncaptured | Self::Failed { .. } => None,
+			Self::Captured { timings, .. } => Some(timings),
+		}
+	}
+}
+
+/// Run a solver on `data`. For "PartSolve"-class solvers, only the given `parts` are solved; if both are, they are
+/// solved together, so part two can build on part one. Each part's answer is passed to `on_solved` as soon as it's known.
+pub fn run_one_solver(
+	data: &str,
+	solver: Solver,
+	parts: &[Part],
+	on_solved: OnSolved,
+) -> Result<SolverOutcome, Box<dyn Error>> {
+	match solver {
+		// "Original"-class solvers simply take an &str, perform their operations, and print the output.
+		Solver::Original(solver) => {
+			solver(data)?;
+
+			Ok(SolverOutcome::Uncaptured)
+		}
+		// "PartSolve"-class solvers have a defined data structure which groups together the parsing & solving logic.
+		Solver::PartSolve(mut part_solver) => {
+			let t_start = Instant::now();
+
+			let intermediate = part_solver.parse(data)?;
+
+			let t_parsed = Instant::now();
+
+			let (mut part_one, mut part_two) = (None, None);
+			let solved_parts = solve_parts(part_solver.as_ref(), intermediate.as_ref(), parts, on_solved);
+			for (part, answer, time) in solved_parts.answers {
+				match part {
+					Part::One => part_one = Some((answer, time)),
+					Part::Two => part_two = Some((answer, time)),
+				}
+			}
+
+			// Parts without an answer have no meaningful time.
+			let answered_time = |part: &Option<(Answer, Duration)>| {
+				part
+					.as_ref()
+					.filter(|(answer, _)| answer.is_answered())
+					.map(|&(_, time)| time)
+			};
+
+			let timings = Timings {
+				parse: t_parsed.duration_since(t_start),
+				part_one: answered_time(&part_one),
+				part_two: answered_time(&part_two),
+				total: t_start.elapsed(),
+				concurrent: solved_parts.concurrent,
+			};
+
+			Ok(SolverOutcome::Captured {
+				part_one: part_one.map(|(answer, _)| answer),
+				part_two: part_two.map(|(answer, _)| answer),
+				timings,
+			})
+		}
+	}
+}
+
+/// The stack size given to each solver's thread; the same as the main thread's on most platforms, since some
+/// solvers recurse deeply.
+const SOLVER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+	payload
+		.downcast_ref::<&str>()
+		.map(ToString::to_string)
+		.or_else(|| payload.downcast_ref::<String>().cloned())
+		.unwrap_or_else(|| "(non-string panic payload)".to_string())
+}
+
+/// How `timeout` is measured for an [`isolate`]d thread.
+#[derive(Clone, Copy, PartialEq)]
+enum Deadline {
+	/// The thread must finish within the timeout.
+	Overall,
+	/// Each message must arrive within the timeout of the one before it.
+	PerMessage,
+}
+
+/// Run `work` on a thread of its own, so that a panic can't take down the rest of the run, handing each message it
+/// sends to `receive`. The thread is given up on if it doesn't keep to `timeout`, as measured by `deadline`.
+///
+/// A thread can't be forcibly stopped, so one which times out is left running in the background until the process
+/// exits.
+fn isolate<M: Send + 'static>(
+	name: String,
+	timeout: Option<Duration>,
+	deadline: Deadline,
+	work: impl FnOnce(mpsc::Sender<M>) + Send + 'static,
+	mut receive: impl FnMut(M),
+) -> Result<(), Failure> {
+	let (tx, rx) = mpsc::channel();
+
+	let handle = std::thread::Builder::new()
+		.name(name)
+		.stack_size(SOLVER_STACK_SIZE)
+		.spawn(move || work(tx))
+		.map_err(|error| Failure::Error(error.to_string()))?;
+
+	let mut t_start = Instant::now();
+
+	loop {
+		let received = match timeout {
+			Some(timeout) => rx.recv_timeout(timeout.saturating_sub(t_start.elapsed())),
+			None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+		};
+
+		match received {
+			Ok(message) => {
+				receive(message);
+
+				if deadline == Deadline::PerMessage {
+					t_start = Instant::now();
+				}
+			}
+			Err(RecvTimeoutError::Timeout) => {
+				return Err(Failure::Timeout(timeout.unwrap_or_default()));
+			}
+			// The sender is dropped once the thread is done, whether it finished or panicked.
+			Err(RecvTimeoutError::Disconnected) => {
+				return handle
+					.join()
+					.map_err(|payload| Failure::Panic(panic_message(payload.as_ref())));
+			}
+		}
+	}
+}
+
+/// What a solver's thread reports back as it runs.
+enum Progress {
+	/// A part has been solved; the other may still be running.
+	Solved(Part, Answer),
+	/// The solver is done.
+	Finished(SolverOutcome),
+}
+
+/// Run the solver for `year` and `day`, with `params` set on it, on a thread of its own, so that a panic can't take
+/// down the rest of the run, giving up on it if it doesn't finish within `timeout`.
+///
+/// The answers to parts solved before a panic or timeout are kept in the failed outcome.
+pub fn run_isolated(
+	year: u16,
+	day: u8,
+	data: String,
+	parts: &[Part],
+	params: &Params,
+	timeout: Option<Duration>,
+) -> SolverOutcome {
+	let parts = parts.to_vec();
+	let params = params.clone();
+
+	let (mut part_one, mut part_two, mut finished) = (None, None, None);
+
+	// `PartSolve` solvers aren't `Send`, so the thread instantiates its own.
+	let isolated = isolate(
+		format!("{year}/day{day:02}"),
+		timeout,
+		Deadline::Overall,
+		move |tx| {
+			let on_solved = |part, answer: &Answer| {
+				// The receiver is gone if the runner already gave up on this solver.
+				let _ = tx.send(Progress::Solved(part, answer.clone()));
+			};
+
+			let outcome = match instantiate_solver(year, day) {
+				Some(mut solver) => params
+					.apply(&mut solver)
+					.map_err(Into::into)
+					.and_then(|()| run_one_solver(&data, solver, &parts, &on_solved))
+					.unwrap_or_else(|error| SolverOutcome::failed(Failure::Error(error.to_string()))),
+				None => SolverOutcome::failed(Failure::Error(format!(
+					"no solver registered for year {year} day {day}"
+				))),
+			};
+
+			let _ = tx.send(Progress::Finished(outcome));
+		},
+		|progress| match progress {
+			Progress::Solved(Part::One, answer) => part_one = Some(answer),
+			Progress::Solved(Part::Two, answer) => part_two = Some(answer),
+			Progress::Finished(outcome) => finished = Some(outcome),
+		},
+	);
+
+	match isolated {
+		Ok(()) => finished.expect("a solver's thread reports its outcome before it finishes"),
+		Err(failure) => SolverOutcome::Failed {
+			failure,
+			part_one,
+			part_two,
+		},
+	}
+}
+
+/// What a benchmark's thread reports back as it runs.
+enum BenchProgress {
+	/// An iteration is done.
+	Iterated,
+	Finished(Result<Option<PhaseSamples>, String>),
+}
+
+/// Benchmark the solver for `year` and `day`, with `params` set on it, on a thread of its own, giving up if any one
+/// iteration doesn't finish within `timeout`; see [`bench::bench_solver`].
+fn bench_isolated(
+	year: u16,
+	day: u8,
+	data: String,
+	config: BenchConfig,
+	parts: &[Part],
+	params: &Params,
+	timeout: Option<Duration>,
+) -> Result<Option<PhaseSamples>, Failure> {
+	let parts = parts.to_vec();
+	let params = params.clone();
+
+	let mut finished = None;
+
+	isolate(
+		format!("{year}/day{day:02}"),
+		timeout,
+		Deadline::PerMessage,
+		move |tx| {
+			// Each iteration gets a fresh solver instance, so state can't leak from one iteration to the next.
+			let make_solver = || -> Result<Solver, Box<dyn Error>> {
+				let mut solver = instantiate_solver(year, day)
+					.ok_or_else(|| format!("no solver registered for year {year} day {day}"))?;
+				params.apply(&mut solver)?;
+				Ok(solver)
+			};
+
+			let benched = bench::bench_solver(&data, config, &parts, make_solver, || {
+				let _ = tx.send(BenchProgress::Iterated);
+			});
+
+			let _ = tx.send(BenchProgress::Finished(
+				benched.map_err(|error| error.to_string()),
+			));
+		},
+		|progress| {
+			if let BenchProgress::Finished(benched) = progress {
+				finished = Some(benched);
+			}
+		},
+	)?;
+
+	finished
+		.expect("a benchmark's thread reports its samples before it finishes")
+		.map_err(Failure::Error)
+}
+
+/// Counts of how each selected solver fared.
+#[derive(Default)]
+pub struct Tally {
+	pub completed: usize,
+	/// Solvers which weren't run, e.g. because their input is missing.
+	pub skipped: usize,
+	pub errored: usize,
+	pub panicked: usize,
+	pub timed_out: usize,
+}
+
+impl Tally {
+	fn absorb(&mut self, other: &Tally) {
+		self.completed += other.completed;
+		self.skipped += other.skipped;
+		self.errored += other.errored;
+		self.panicked += other.panicked;
+		self.timed_out += other.timed_out;
+	}
+
+	fn count(&mut self, outcome: &SolverOutcome) {
+		match outcome {
+			SolverOutcome::Uncaptured | SolverOutcome::Captured { .. } => self.completed += 1,
+			SolverOutcome::Failed { failure, .. } => self.count_failure(failure),
+		}
+	}
+
+	fn count_failure(&mut self, failure: &Failure) {
+		match failure {
+			Failure::Error(_) => self.errored += 1,
+			Failure::Panic(_) => self.panicked += 1,
+			Failure::Timeout(_) => self.timed_out += 1,
+		}
+	}
+
+	/// Returns true if any solver failed to run to completion.
+	pub fn has_failures(&self) -> bool {
+		self.errored + self.panicked + self.timed_out > 0
+	}
+}
+
+impl core::fmt::Display for Tally {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"{} completed, {} skipped, {} errored, {} panicked, {} timed out",
+			self.completed, self.skipped, self.errored, self.panicked, self.timed_out
+		)
+	}
+}
+
+/// What running one or more selected solvers contributed to the overall run.
+#[derive(Default)]
+pub struct RunSummary {
+	/// Number of parts whose answers did not match the recorded answers.
+	pub mismatches: usize,
+	/// Number of solvers whose answers file couldn't be read, so their answers went unverified.
+	pub unreadable_answers: usize,
+	/// One record per (year, day, part) that was run.
+	pub records: Vec<Record>,
+	/// One result per solver that was selected, for the end-of-run summary.
+	pub solvers: Vec<SolverResult>,
+	pub tally: Tally,
+}
+
+impl RunSummary {
+	fn absorb(&mut self, other: RunSummary) {
+		self.mismatches += other.mismatches;
+		self.unreadable_answers += other.unreadable_answers;
+		self.records.extend(other.records);
+		self.solvers.extend(other.solvers);
+		self.tally.absorb(&other.tally);
+	}
+
+	fn skipped(year: u16, day: u8) -> Self {
+		Self {
+			solvers: vec![SolverResult::skipped(year, day)],
+			tally: Tally {
+				skipped: 1,
+				..Tally::default()
+			},
+			..Self::default()
+		}
+	}
+}
+
+/// Progress messages go to `err` when `out` is reserved for a machine-readable report.
+fn progress<'w>(
+	options: &RunOptions,
+	out: &'w mut dyn Write,
+	err: &'w mut dyn Write,
+) -> &'w mut dyn Write {
+	match options.format {
+		ReportFormat::Text => out,
+		ReportFormat::Json | ReportFormat::Csv => err,
+	}
+}
+
+/// Find the input for, run, and report on a single selected solver.
+pub fn run_selected_solver(
+	year: u16,
+	day: u8,
+	kind: SolverKind,
+	parts: &[Part],
+	options: &RunOptions,
+	out: &mut dyn Write,
+	err: &mut dyn Write,
+) -> Result<RunSummary, Box<dyn Error>> {
+	writeln!(
+		progress(options, out, err),
+		"Running solver for year {year} day {day}:"
+	)?;
+
+	let input = match InputSource::locate(year, day, options.input.as_ref()) {
+		Ok(input) => input,
+		Err(error) => {
+			writeln!(
+				err,
+				"Error determining input file path for year {year} day {day}: {error}. Skipped."
+			)?;
+			return Ok(RunSummary::skipped(year, day));
+		}
+	};
+
+	let data = match input.read() {
+		Ok(data) => data,
+		Err(error) => {
+			writeln!(err, "Error reading input {input}: {error}. Skipped.")?;
+			return Ok(RunSummary::skipped(year, day));
+		}
+	};
+
+	if let Some(config) = options.bench {
+		writeln!(
+			out,
+			"  (benchmarking: {} iteration(s) after {} warm-up)",
+			config.iterations, config.warmup
+		)?;
+
+		let mut summary = RunSummary::default();
+
+		match bench_isolated(
+			year,
+			day,
+			data,
+			config,
+			parts,
+			&options.params,
+			options.timeout,
+		) {
+			Ok(Some(samples)) => {
+				bench::print_samples(&samples, out)?;
+				summary.tally.completed += 1;
+			}
+			Ok(None) => {
+				writeln!(
+					err,
+					"Solver for year {year} day {day} cannot be benchmarked by phase. Skipped."
+				)?;
+				summary.tally.skipped += 1;
+			}
+			Err(failure) => {
+				writeln!(out, "  ({failure})")?;
+				summary.tally.count_failure(&failure);
+			}
+		}
+
+		return Ok(summary);
+	}
+
+	let mut summary = RunSummary::default();
+
+	// Answers can only be recorded next to inputs which are files. A broken answers file fails the run rather than
+	// quietly turning verification off.
+	let answers_file_path = input.path().map(RecordedAnswers::path_for);
+	let recorded = match answers_file_path.as_deref().map(RecordedAnswers::load) {
+		Some(Ok(recorded)) => recorded,
+		Some(Err(error)) => {
+			writeln!(
+				err,
+				"Error reading answers file {}: {error}. Answers will not be verified.",
+				answers_file_path.unwrap_or_default().display()
+			)?;
+			summary.unreadable_answers += 1;
+			RecordedAnswers::default()
+		}
+		None => RecordedAnswers::default(),
+	};
+
+	let outcome = match (options.format, kind) {
+		// "Original"-class solvers print straight to stdout, which would corrupt a machine-readable report,
+		// so they are listed in the report without being run.
+		(ReportFormat::Json | ReportFormat::Csv, SolverKind::Original) => SolverOutcome::Uncaptured,
+		_ => run_isolated(year, day, data, parts, &options.params, options.timeout),
+	};
+
+	summary.tally.count(&outcome);
+
+	if options.format == ReportFormat::Text {
+		report::print_outcome(&outcome, &recorded, parts, out)?;
+	}
+
+	// Records are kept in every format: they feed the timing history as well as the machine-readable reports.
+	let records = Record::from_outcome(year, day, &outcome, &recorded, parts);
+	summary
+		.solvers
+		.push(SolverResult::from_outcome(year, day, &outcome, &records));
+
+	for record in records {
+		if matches!(record.verdict, Some(Verdict::Fail { .. })) {
+			summary.mismatches += 1;
+		}
+
+		summary.records.push(record);
+	}
+
+	Ok(summary)
+}
+
+/// Run all selected solvers, one after another or on a thread pool depending on `options.jobs`.
+pub fn run_all(
+	solvers: Vec<(u16, u8, Solver)>,
+	parts: &[Part],
+	options: &RunOptions,
+) -> Result<RunSummary, Box<dyn Error>> {
+	// Solvers are instantiated afresh wherever they're run, so only their kinds are needed from here on.
+	let solvers: Vec<(u16, u8, SolverKind)> = solvers
+		.into_iter()
+		.map(|(year, day, solver)| (year, day, solver.kind()))
+		.collect();
+
+	let selected: Vec<(u16, u8)> = solvers.iter().map(|(year, day, _)| (*year, *day)).collect();
+	InputSource::check_override(options.input.as_ref(), &selected)?;
+
+	// Every selected solver must accept every parameter, so a mistyped name fails the run up front.
+	if !options.params.is_empty() {
+		for &(year, day) in &selected {
+			if let Some(mut solver) = instantiate_solver(year, day) {
+				options
+					.params
+					.apply(&mut solver)
+					.map_err(|error| format!("--param for year {year} day {day}: {error}"))?;
+			}
+		}
+	}
+
+	let mut stdout = std::io::stdout();
+	let mut stderr = std::io::stderr();
+
+	writeln!(
+		progress(options, &mut stdout, &mut stderr),
+		"Running {} solver(s).",
+		solvers.len()
+	)?;
+
+	let summary = if let Some(jobs) = options.jobs {
+		run_all_parallel(&solvers, parts, options, jobs)?
+	} else {
+		let mut summary = RunSummary::default();
+
+		for &(year, day, kind) in &solvers {
+			summary.absorb(run_selected_solver(
+				year,
+				day,
+				kind,
+				parts,
+				options,
+				&mut stdout,
+				&mut stderr,
+			)?);
+		}
+
+		summary
+	};
+
+	writeln!(
+		progress(options, &mut stdout, &mut stderr),
+		"Tally: {}.",
+		summary.tally
+	)?;
+
+	// The summary grid would only get in the way of a machine-readable report or a table of benchmarks.
+	if options.format == ReportFormat::Text && options.bench.is_none() {
+		summary::print_summary(&summary.solvers, summary::use_color(&stdout), &mut stdout)?;
+	}
+
+	Ok(summary)
+}
+
+/// The buffered output of a solver which ran on the thread pool.
+struct BufferedRun {
+	out: Vec<u8>,
+	err: Vec<u8>,
+	result: Result<RunSummary, String>,
+	elapsed: Duration,
+}
+
+fn run_all_parallel(
+	solvers: &[(u16, u8, SolverKind)],
+	parts: &[Part],
+	options: &RunOptions,
+	jobs: usize,
+) -> Result<RunSummary, Box<dyn Error>> {
+	let t_start = Instant::now();
+
+	let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+
+	// "Original"-class solvers print straight to stdout, so they can't be buffered and instead run in order on
+	// this thread once the pool is done. The rest run on the pool.
+	let pooled: Vec<Option<(u16, u8, SolverKind)>> = solvers
+		.iter()
+		.map(|&(year, day, kind)| match kind {
+			SolverKind::Original if options.format == ReportFormat::Text => None,
+			_ => Some((year, day, kind)),
+		})
+		.collect();
+
+	let buffered: Vec<Option<BufferedRun>> = pool.install(|| {
+		pooled
+			.par_iter()
+			.map(|selected| {
+				let (year, day, kind) = (*selected)?;
+				let t_start = Instant::now();
+
+				let mut out = Vec::new();
+				let mut err = Vec::new();
+
+				let result = run_selected_solver(year, day, kind, parts, options, &mut out, &mut err)
+					.map_err(|error| error.to_string());
+
+				Some(BufferedRun {
+					out,
+					err,
+					result,
+					elapsed: t_start.elapsed(),
+				})
+			})
+			.collect()
+	});
+
+	let mut stdout = std::io::stdout();
+	let mut stderr = std::io::stderr();
+
+	let mut summary = RunSummary::default();
+	// The sum of each solver's wall-clock time, which is only a stand-in for the CPU time the run took.
+	let mut summed = Duration::ZERO;
+
+	for (&(year, day, kind), buffered) in solvers.iter().zip(buffered) {
+		if let Some(BufferedRun {
+			out,
+			err,
+			result,
+			elapsed,
+		}) = buffered
+		{
+			stderr.write_all(&err)?;
+			stdout.write_all(&out)?;
+			summed += elapsed;
+			summary.absorb(result?);
+		} else {
+			let t_start = Instant::now();
+			summary.absorb(run_selected_solver(
+				year,
+				day,
+				kind,
+				parts,
+				options,
+				&mut stdout,
+				&mut stderr,
+			)?);
+			summed += t_start.elapsed();
+		}
+	}
+
+	let wall = t_start.elapsed();
+
+	writeln!(
+		progress(options, &mut stdout, &mut stderr),
+		"Ran on {jobs} thread(s) in {wall:.1?} wall-clock time ({summed:.1?} summed solver wall time, {:.1}x).",
+		summed.as_secs_f64() / wall.as_secs_f64().max(f64::EPSILON),
+	)?;
+
+	Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+
+	use super::{Deadline, Failure, isolate};
+
+	#[test]
+	fn isolate_keeps_messages_sent_before_a_panic() {
+		let mut received = Vec::new();
+		let isolated = isolate(
+			"panics".to_string(),
+			None,
+			Deadline::Overall,
+			|tx| {
+				tx.send(1).unwrap();
+				panic!("boom");
+			},
+			|message| received.push(message),
+		);
+
+		assert_eq!(vec![1], received);
+		assert!(matches!(isolated, Err(Failure::Panic(message)) if message == "boom"));
+	}
+
+	#[test]
+	fn isolate_times_out() {
+		let mut received = Vec::new();
+		let isolated = isolate(
+			"sleeps".to_string(),
+			Some(Duration::from_millis(50)),
+			Deadline::Overall,
+			|tx| {
+				tx.send(1).unwrap();
+				std::thread::sleep(Duration::from_secs(5));
+			},
+			|message| received.push(message),
+		);
+
+		assert_eq!(vec![1], received);
+		assert!(matches!(isolated, Err(Failure::Timeout(_))));
+	}
+
+	#[test]
+	fn isolate_restarts_the_clock_per_message() {
+		let mut received = 0;
+		let isolated = isolate(
+			"ticks".to_string(),
+			Some(Duration::from_millis(200)),
+			Deadline::PerMessage,
+			|tx| {
+				for _ in 0..5 {
+					std::thread::sleep(Duration::from_millis(60));
+					tx.send(()).unwrap();
+				}
+			},
+			|()| received += 1,
+		);
+
+		assert_eq!(5, received);
+		assert!(isolated.is_ok());
+	}
+}
@@ -0,0 +1,304 @@
+use std::{
+	fs,
+	io::Write,
+	path::{Path, PathBuf},
+};
+
+/// The day every new day is copied from.
+const TEMPLATE: &str = "src/dtemplate/day01.rs";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScaffoldError {
+	#[error("usage: new YEAR DAY")]
+	Usage,
+	#[error("invalid year {0}; expected 2015 or later")]
+	InvalidYear(String),
+	#[error("invalid day {0}; expected 1 to 25")]
+	InvalidDay(String),
+	#[error("{year} day {day} already exists at {}", path.display())]
+	DayExists { year: u16, day: u8, path: PathBuf },
+	#[error("{} is not inside the repository; run `new` from within it", cwd.display())]
+	NotInRepository { cwd: PathBuf },
+	#[error("could not find the list of default features in Cargo.toml")]
+	UnrecognizedManifest,
+	#[error("I/O error occurred while scaffolding: {0}")]
+	Io(#[from] std::io::Error),
+}
+
+/// What scaffolding a day changed, relative to the repository root.
+#[derive(Debug, Default, PartialEq)]
+pub struct Scaffolded {
+	pub created: Vec<PathBuf>,
+	/// Whether the year's Cargo feature had to be added.
+	pub added_feature: bool,
+}
+
+/// Parse the `YEAR DAY` arguments of the `new` command.
+pub fn parse_args(args: &[String]) -> Result<(u16, u8), ScaffoldError> {
+	let [year, day] = args else {
+		return Err(ScaffoldError::Usage);
+	};
+
+	let year = year
+		.parse()
+		.ok()
+		.filter(|year| *year >= 2015)
+		.ok_or_else(|| ScaffoldError::InvalidYear(year.clone()))?;
+	let day = day
+		.parse()
+		.ok()
+		.filter(|day| (1..=25).contains(day))
+		.ok_or_else(|| ScaffoldError::InvalidDay(day.clone()))?;
+
+	Ok((year, day))
+}
+
+/// Find the root of the repository containing `cwd`: the nearest directory with both a `Cargo.toml` and the day
+/// template.
+pub fn find_root(cwd: &Path) -> Result<PathBuf, ScaffoldError> {
+	cwd
+		.ancestors()
+		.find(|dir| dir.join("Cargo.toml").is_file() && dir.join(TEMPLATE).is_file())
+		.map(Path::to_path_buf)
+		.ok_or_else(|| ScaffoldError::NotInRepository {
+			cwd: cwd.to_path_buf(),
+		})
+}
+
+/// Start a new day in the repository at `root`: copy the template into the year's directory, enable the year's
+/// Cargo feature, and create empty input, example and example answers files for it.
+///
+/// A year which has been archived keeps its new days (and inputs) in the archive. Existing input and example files are
+/// left alone, but an existing day is never overwritten.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Scaffolded, ScaffoldError> {
+	let archived = root.join(format!("src/archive/d{year}")).is_dir();
+	let (year_dir, inputs_dir) = if archived {
+		(
+			format!("src/archive/d{year}"),
+			format!("inputs/archive/{year}"),
+		)
+	} else {
+		(format!("src/d{year}"), format!("inputs/{year}"))
+	};
+
+	let module = Path::new(&year_dir).join(format!("day{day:02}.rs"));
+	if root.join(&module).exists() {
+		return Err(ScaffoldError::DayExists {
+			year,
+			day,
+			path: module,
+		});
+	}
+
+	let template = fs::read_to_string(root.join(TEMPLATE))?;
+
+	let manifest_path = root.join("Cargo.toml");
+	let manifest = fs::read_to_string(&manifest_path)?;
+	// Work out the feature before creating anything, but only enable it once the day is in place to build.
+	let updated_manifest = add_year_feature(&manifest, year)?;
+
+	let mut created = Vec::new();
+
+	fs::create_dir_all(root.join(&year_dir))?;
+	fs::File::create_new(root.join(&module))?.write_all(template.as_bytes())?;
+	created.push(module);
+
+	fs::create_dir_all(root.join(&inputs_dir))?;
+	for path in [
+		Path::new(&inputs_dir).join(format!("day{day:02}.txt")),
+		Path::new(&inputs_dir).join(format!("day{day:02}.example1.txt")),
+		Path::new(&inputs_dir).join(format!("day{day:02}.example1.answers")),
+	] {
+		if !root.join(&path).exists() {
+			fs::File::create_new(root.join(&path))?;
+			created.push(path);
+		}
+	}
+
+	let added_feature = match updated_manifest {
+		Some(manifest) => {
+			fs::write(&manifest_path, manifest)?;
+			true
+		}
+		None => false,
+	};
+
+	Ok(Scaffolded {
+		created,
+		added_feature,
+	})
+}
+
+/// Add the `yYYYY` feature for `year` to `manifest`, both as a feature and to the default features, keeping each list
+/// in year order.
+///
+/// Returns `None` if the feature already exists.
+pub fn add_year_feature(manifest: &str, year: u16) -> Result<Option<String>, ScaffoldError> {
+	let feature = format!("y{year}");
+	let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+
+	let features = lines
+		.iter()
+		.position(|line| line.trim() == "[features]")
+		.ok_or(ScaffoldError::UnrecognizedManifest)?;
+	let end = lines[features + 1..]
+		.iter()
+		.position(|line| line.starts_with('['))
+		.map_or(lines.len(), |offset| features + 1 + offset);
+
+	if lines[features + 1..end]
+		.iter()
+		.any(|line| key(line) == feature)
+	{
+		return Ok(None);
+	}
+
+	// A year's own feature goes among the other years' features.
+	let year_features: Vec<usize> = (features + 1..end)
+		.filter(|&index| year_feature(key(&lines[index])).is_some())
+		.collect();
+	let at = year_features
+		.iter()
+		.find(|&&index| year_feature(key(&lines[index])) > Some(year))
+		.copied()
+		.or_else(|| year_features.last().map(|index| index + 1))
+		.unwrap_or(end);
+	lines.insert(at, format!("{feature} = []"));
+	let end = end + 1;
+
+	// The default list is written one feature per line.
+	let default = (features + 1..end)
+		.find(|&index| lines[index].trim_start().starts_with("default = ["))
+		.ok_or(ScaffoldError::UnrecognizedManifest)?;
+	let close = (default + 1..lines.len())
+		.find(|&index| lines[index].trim() == "]")
+		.ok_or(ScaffoldError::UnrecognizedManifest)?;
+	let indent = lines[default + 1..close]
+		.first()
+		.map_or("    ", |line| &line[..line.len() - line.trim_start().len()])
+		.to_string();
+	let at = (default + 1..close)
+		.find(|&index| year_feature(lines[index].trim().trim_matches([',', '"'])) > Some(year))
+		.unwrap_or(close);
+	lines.insert(at, format!("{indent}\"{feature}\","));
+
+	let mut manifest = lines.join("\n");
+	manifest.push('\n');
+
+	Ok(Some(manifest))
+}
+
+/// The key of a `key = value` line.
+fn key(line: &str) -> &str {
+	line.split('=').next().unwrap_or_default().trim()
+}
+
+/// The year named by a `yYYYY` feature.
+fn year_feature(name: &str) -> Option<u16> {
+	name.trim().strip_prefix('y')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{fs, path::PathBuf};
+
+	use super::{ScaffoldError, add_year_feature, find_root, new_day, parse_args};
+
+	const MANIFEST: &str = "\
+[package]
+name = \"aoc\"
+
+[features]
+default = [
+    \"y2015\",
+    \"y2024\",
+]
+y2015 = []
+y2024 = []
+
+[lints.clippy]
+all = \"deny\"
+";
+
+	fn args(args: &[&str]) -> Vec<String> {
+		args.iter().map(ToString::to_string).collect()
+	}
+
+	#[test]
+	fn parses_year_and_day() {
+		assert_eq!((2026, 1), parse_args(&args(&["2026", "1"])).unwrap());
+		assert!(matches!(
+			parse_args(&args(&["2026"])),
+			Err(ScaffoldError::Usage)
+		));
+		assert!(matches!(
+			parse_args(&args(&["2014", "1"])),
+			Err(ScaffoldError::InvalidYear(_))
+		));
+		assert!(matches!(
+			parse_args(&args(&["2026", "26"])),
+			Err(ScaffoldError::InvalidDay(_))
+		));
+	}
+
+	#[test]
+	fn adds_year_features_in_order() {
+		let added = add_year_feature(MANIFEST, 2026).unwrap().unwrap();
+		assert_eq!(
+			MANIFEST
+				.replace("    \"y2024\",\n", "    \"y2024\",\n    \"y2026\",\n")
+				.replace("y2024 = []\n", "y2024 = []\ny2026 = []\n"),
+			added
+		);
+
+		let added = add_year_feature(MANIFEST, 2016).unwrap().unwrap();
+		assert_eq!(
+			MANIFEST
+				.replace("    \"y2024\",\n", "    \"y2016\",\n    \"y2024\",\n")
+				.replace("y2024 = []\n", "y2016 = []\ny2024 = []\n"),
+			added
+		);
+
+		assert_eq!(None, add_year_feature(MANIFEST, 2024).unwrap());
+	}
+
+	#[test]
+	fn scaffolds_a_day_once() {
+		let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+		fs::create_dir_all(root.join("src/dtemplate")).unwrap();
+		fs::write(root.join("src/dtemplate/day01.rs"), "// template\n").unwrap();
+		fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+		fs::create_dir_all(root.join("src/d2024")).unwrap();
+		assert_eq!(root, find_root(&root.join("src/d2024")).unwrap());
+		assert!(matches!(
+			find_root(&std::env::temp_dir()),
+			Err(ScaffoldError::NotInRepository { .. })
+		));
+
+		let scaffolded = new_day(&root, 2026, 3).unwrap();
+		assert!(scaffolded.added_feature);
+		assert_eq!(
+			vec![
+				PathBuf::from("src/d2026/day03.rs"),
+				PathBuf::from("inputs/2026/day03.txt"),
+				PathBuf::from("inputs/2026/day03.example1.txt"),
+				PathBuf::from("inputs/2026/day03.example1.answers"),
+			],
+			scaffolded.created
+		);
+		assert_eq!(
+			"// template\n",
+			fs::read_to_string(root.join("src/d2026/day03.rs")).unwrap()
+		);
+		assert!(
+			fs::read_to_string(root.join("Cargo.toml"))
+				.unwrap()
+				.contains("y2026 = []")
+		);
+
+		let again = new_day(&root, 2026, 3);
+		fs::remove_dir_all(&root).unwrap();
+		assert!(matches!(again, Err(ScaffoldError::DayExists { .. })));
+	}
+}
@@ -1,4 +1,7 @@
-use core::any::Any;
+use core::{any::Any, time::Duration};
+use std::time::Instant;
+
+use crate::params::{Param, ParamError};
 
 /// Describes the behavior of common Advent of Code solvers
 ///
@@ -14,15 +17,15 @@ use core::any::Any;
 ///
 /// - Part Solutions are fully independent from each other. They cannot mutate any shared state through the trait.
 ///
-/// - Part Solutions can return `None` if they do not have an answer to provide (yet).
-///
-/// - Part Solutions return their answer as a heap-allocated `String`. This is flexible enough to handle days where
-///   alphanumeric output is required to solve the puzzle (e.g. password challenges).
+/// - Part Solutions return an [`Answer`], which tells integer, text and picture answers apart from parts which are
+///   not implemented (yet) or failed to compute.
 ///
-/// - In theory, one could run both parts in parallel, since they ought to be independent.
+/// - Since the parts are independent, they can be run in parallel: wrap a [`TypedPartSolve`] in [`Concurrent`] to
+///   opt in.
 ///
 /// - The intermediate data structure is type-erased to `Box<dyn Any>`, allowing the implementer to choose any type
-///   they wish to use as their "Intermediate".
+///   they wish to use as their "Intermediate". [`TypedPartSolve`] offers the same flexibility with the types checked
+///   at compile time, and is usually the better choice for new solvers.
 pub trait PartSolve {
 	/// Parse the provided `input` to an intermediate type.
 	/// The resulting data will be passed in to `part_one` and `part_two`.
@@ -49,12 +52,12 @@ pub trait PartSolve {
 	///#
 	///     // parse, part_two omitted for brevity
 	///
-	///     fn part_one(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
-	///         Some("42".to_string())
+	///     fn part_one(&self, _intermediate: &dyn core::any::Any) -> aoc::Answer {
+	///         42.into()
 	///     }
 	///#
-	///#    fn part_two(&self, _intermediate: &dyn core::any::Any) -> Option<String> {
-	///#        Some("42".to_string())
+	///#    fn part_two(&self, _intermediate: &dyn core::any::Any) -> aoc::Answer {
+	///#        aoc::Answer::NotImplemented
 	///#    }
 	/// }
 	/// ```
@@ -62,7 +65,7 @@ pub trait PartSolve {
 	/// To access the intermediate data, use [`(dyn Any)::downcast_ref`]:
 	///
 	/// ```
-	/// # use aoc::PartSolve;
+	/// # use aoc::{Answer, PartSolve};
 	/// struct Solution;
 	///
 	/// impl aoc::PartSolve for Solution {
@@ -73,27 +76,663 @@ pub trait PartSolve {
 	///     }
 	///
 	///     // ... sum them for part one ...
-	///     fn part_one(&self, numbers: &dyn core::any::Any) -> Option<String> {
-	///         let numbers: &Vec<u32> = numbers.downcast_ref()?;
-	///         let sum: u32 = numbers.iter().sum();
-	///         Some(sum.to_string())
+	///     fn part_one(&self, numbers: &dyn core::any::Any) -> Answer {
+	///         let Some(numbers) = numbers.downcast_ref::<Vec<u32>>() else {
+	///             return Answer::Error("unexpected intermediate".to_string());
+	///         };
+	///         numbers.iter().sum::<u32>().into()
 	///     }
 	///
 	///     // ... and product them for part two.
-	///     fn part_two(&self, numbers: &dyn core::any::Any) -> Option<String> {
-	///         let numbers: &Vec<u32> = numbers.downcast_ref()?;
-	///         let sum: u32 = numbers.iter().product();
-	///         Some(sum.to_string())
+	///     fn part_two(&self, numbers: &dyn core::any::Any) -> Answer {
+	///         let Some(numbers) = numbers.downcast_ref::<Vec<u32>>() else {
+	///             return Answer::Error("unexpected intermediate".to_string());
+	///         };
+	///         numbers.iter().product::<u32>().into()
 	///     }
 	/// }
 	///
 	/// let mut solver = Solution;
 	/// let intermediate = solver.parse("1,2,3,4").unwrap();
-	/// assert_eq!(Some("10".to_string()), solver.part_one(intermediate.as_ref()));
-	/// assert_eq!(Some("24".to_string()), solver.part_two(intermediate.as_ref()));
+	/// assert_eq!(Answer::Integer(10), solver.part_one(intermediate.as_ref()));
+	/// assert_eq!(Answer::Integer(24), solver.part_two(intermediate.as_ref()));
 	/// ```
-	fn part_one(&self, intermediate: &dyn Any) -> Option<String>;
+	fn part_one(&self, intermediate: &dyn Any) -> Answer;
 
 	/// Solve the second part of the puzzle.
-	fn part_two(&self, intermediate: &dyn Any) -> Option<String>;
+	fn part_two(&self, intermediate: &dyn Any) -> Answer;
+
+	/// Solve both parts of the puzzle in turn, timing each, and passing each answer to `on_solved` as soon as it's
+	/// known.
+	///
+	/// The runner calls this instead of `part_one` and `part_two` when both parts are selected, so that solvers
+	/// whose second part builds on the first can hand it over. By default, the parts are solved independently.
+	fn solve_both(&self, intermediate: &dyn Any, on_solved: OnSolved) -> BothAnswers {
+		let (part_one, part_one_time) = timed(|| self.part_one(intermediate));
+		on_solved(Part::One, &part_one);
+		let (part_two, part_two_time) = timed(|| self.part_two(intermediate));
+		on_solved(Part::Two, &part_two);
+
+		BothAnswers {
+			part_one,
+			part_one_time,
+			part_two,
+			part_two_time,
+			concurrent: false,
+		}
+	}
+
+	/// The parameters this solver reads, with their current values; see [`crate::params`]. A fresh solver's values
+	/// are its defaults. By default, a solver has none.
+	fn params(&self) -> Vec<Param> {
+		Vec::new()
+	}
+
+	/// Set one of the parameters declared by [`params`](Self::params), before the input is parsed.
+	///
+	/// # Errors
+	///
+	/// If `name` isn't declared, or `value` isn't valid for it.
+	fn set_param(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+		Err(ParamError::unknown(name, &self.params()))
+	}
+}
+
+/// Receives each part's answer as soon as it's solved, so that it isn't lost if the other part never finishes.
+pub type OnSolved<'a> = &'a (dyn Fn(Part, &Answer) + Sync);
+
+/// The answers to both parts of a puzzle, and how long each took to compute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BothAnswers {
+	pub part_one: Answer,
+	pub part_one_time: Duration,
+	pub part_two: Answer,
+	pub part_two_time: Duration,
+	/// Whether the parts were solved at the same time, on separate threads.
+	pub concurrent: bool,
+}
+
+/// The answers to the selected parts of a puzzle, as solved by [`solve_parts`].
+pub struct SolvedParts {
+	/// Each selected part's answer, and how long it took to compute.
+	pub answers: Vec<(Part, Answer, Duration)>,
+	/// Whether the parts were solved at the same time, on separate threads.
+	pub concurrent: bool,
+}
+
+/// Solve the given `parts` of a puzzle, timing each and passing each answer to `on_solved` as soon as it's known. If
+/// both parts are given, they are solved together with [`PartSolve::solve_both`].
+pub fn solve_parts(
+	solver: &dyn PartSolve,
+	intermediate: &dyn Any,
+	parts: &[Part],
+	on_solved: OnSolved,
+) -> SolvedParts {
+	if parts.contains(&Part::One) && parts.contains(&Part::Two) {
+		let both = solver.solve_both(intermediate, on_solved);
+
+		return SolvedParts {
+			answers: vec![
+				(Part::One, both.part_one, both.part_one_time),
+				(Part::Two, both.part_two, both.part_two_time),
+			],
+			concurrent: both.concurrent,
+		};
+	}
+
+	SolvedParts {
+		answers: parts
+			.iter()
+			.map(|&part| {
+				let (answer, time) = timed(|| match part {
+					Part::One => solver.part_one(intermediate),
+					Part::Two => solver.part_two(intermediate),
+				});
+				on_solved(part, &answer);
+
+				(part, answer, time)
+			})
+			.collect(),
+		concurrent: false,
+	}
+}
+
+fn timed<T>(solve: impl FnOnce() -> T) -> (T, Duration) {
+	let t_start = Instant::now();
+	let result = solve();
+
+	(result, t_start.elapsed())
+}
+
+/// A statically-typed variant of [`PartSolve`], where the intermediate and answers have concrete types.
+///
+/// Every `TypedPartSolve` is also a [`PartSolve`] through a blanket implementation, so it can be registered as a
+/// `Solver::PartSolve` like any other solver. The blanket implementation boxes the intermediate and converts answers
+/// into an [`Answer`], with `Ok(None)` meaning the part is not implemented and an `Err` meaning it failed.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::{PartSolve, TypedPartSolve};
+///
+/// struct Solution;
+///
+/// impl TypedPartSolve for Solution {
+///     type Intermediate = Vec<u32>;
+///     type PartOne = u32;
+///     type PartTwo = u32;
+///
+///     fn parse(&mut self, input: &str) -> anyhow::Result<Vec<u32>> {
+///         Ok(input.split(',').map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part_one(&self, numbers: &Vec<u32>) -> anyhow::Result<Option<u32>> {
+///         Ok(Some(numbers.iter().sum()))
+///     }
+///
+///     fn part_two(&self, numbers: &Vec<u32>) -> anyhow::Result<Option<u32>> {
+///         Ok(Some(numbers.iter().product()))
+///     }
+/// }
+///
+/// let mut solver = Solution;
+///
+/// // Used directly, everything is typed...
+/// let numbers = TypedPartSolve::parse(&mut solver, "1,2,3,4").unwrap();
+/// assert_eq!(Some(10), TypedPartSolve::part_one(&solver, &numbers).unwrap());
+///
+/// // ... and through `PartSolve`, it behaves like any other solver.
+/// let intermediate = PartSolve::parse(&mut solver, "1,2,3,4").unwrap();
+/// assert_eq!(aoc::Answer::Integer(24), PartSolve::part_two(&solver, intermediate.as_ref()));
+/// ```
+pub trait TypedPartSolve {
+	/// The data produced by parsing, shared by both parts.
+	type Intermediate: 'static;
+	/// The answer to part one. It's cloned to be reported before part two, which may build on it, is solved.
+	type PartOne: Into<Answer> + Clone;
+	/// The answer to part two.
+	type PartTwo: Into<Answer>;
+
+	/// Parse the provided `input` to the intermediate type.
+	///
+	/// # Errors
+	///
+	/// If parsing fails for an unrecoverable reason, implementations can/should return an `Err` value.
+	fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate>;
+
+	/// Solve the first part of the puzzle, or return `Ok(None)` if it isn't implemented (yet).
+	///
+	/// # Errors
+	///
+	/// If no answer can be computed from the intermediate, e.g. because the puzzle has no solution.
+	fn part_one(&self, intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>>;
+
+	/// Solve the second part of the puzzle, or return `Ok(None)` if it isn't implemented (yet).
+	///
+	/// # Errors
+	///
+	/// If no answer can be computed from the intermediate, e.g. because the puzzle has no solution.
+	fn part_two(&self, intermediate: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>>;
+
+	/// Solve the second part of the puzzle, building on part one's answer.
+	///
+	/// When both parts are run together, this is called in place of `part_two` whenever part one had an answer.
+	/// By default, that answer is ignored.
+	///
+	/// # Errors
+	///
+	/// As for [`part_two`](Self::part_two).
+	fn part_two_given(
+		&self,
+		intermediate: &Self::Intermediate,
+		_part_one: &Self::PartOne,
+	) -> anyhow::Result<Option<Self::PartTwo>> {
+		self.part_two(intermediate)
+	}
+
+	/// See [`PartSolve::params`].
+	fn params(&self) -> Vec<Param> {
+		Vec::new()
+	}
+
+	/// See [`PartSolve::set_param`].
+	///
+	/// # Errors
+	///
+	/// If `name` isn't declared, or `value` isn't valid for it.
+	fn set_param(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+		Err(ParamError::unknown(name, &self.params()))
+	}
+}
+
+impl<T: TypedPartSolve> PartSolve for T {
+	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn Any>> {
+		Ok(Box::new(TypedPartSolve::parse(self, input)?))
+	}
+
+	fn part_one(&self, intermediate: &dyn Any) -> Answer {
+		TypedPartSolve::part_one(self, downcast_intermediate::<T>(intermediate)).into()
+	}
+
+	fn part_two(&self, intermediate: &dyn Any) -> Answer {
+		TypedPartSolve::part_two(self, downcast_intermediate::<T>(intermediate)).into()
+	}
+
+	fn solve_both(&self, intermediate: &dyn Any, on_solved: OnSolved) -> BothAnswers {
+		let intermediate = downcast_intermediate::<T>(intermediate);
+
+		let (part_one, part_one_time) = timed(|| TypedPartSolve::part_one(self, intermediate));
+		// Part two is handed part one's typed answer, so the copy reported straight away is converted from a clone.
+		let (part_one, part_one_answer) = match part_one {
+			Ok(Some(part_one)) => {
+				let answer: Answer = part_one.clone().into();
+				(Some(part_one), answer)
+			}
+			unanswered => (None, Answer::from(unanswered)),
+		};
+		on_solved(Part::One, &part_one_answer);
+		let (part_two, part_two_time) = timed(|| match &part_one {
+			Some(part_one) => self.part_two_given(intermediate, part_one),
+			None => TypedPartSolve::part_two(self, intermediate),
+		});
+		let part_two: Answer = part_two.into();
+		on_solved(Part::Two, &part_two);
+
+		BothAnswers {
+			part_one: part_one_answer,
+			part_one_time,
+			part_two,
+			part_two_time,
+			concurrent: false,
+		}
+	}
+
+	fn params(&self) -> Vec<Param> {
+		TypedPartSolve::params(self)
+	}
+
+	fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+		TypedPartSolve::set_param(self, name, value)
+	}
+}
+
+/// Opts a [`TypedPartSolve`] into solving both of its parts at the same time, on rayon's thread pool.
+///
+/// The solver and its intermediate are shared by both parts, so they must be `Sync`. Part two can't build on part
+/// one's answer when they run side by side, so [`TypedPartSolve::part_two_given`] is never called.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::{Concurrent, PartSolve, TypedPartSolve};
+///
+/// struct Solution;
+///
+/// impl TypedPartSolve for Solution {
+///     type Intermediate = Vec<u32>;
+///     type PartOne = u32;
+///     type PartTwo = u32;
+///
+///     fn parse(&mut self, input: &str) -> anyhow::Result<Vec<u32>> {
+///         Ok(input.split(',').map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part_one(&self, numbers: &Vec<u32>) -> anyhow::Result<Option<u32>> {
+///         Ok(Some(numbers.iter().sum()))
+///     }
+///
+///     fn part_two(&self, numbers: &Vec<u32>) -> anyhow::Result<Option<u32>> {
+///         Ok(Some(numbers.iter().product()))
+///     }
+/// }
+///
+/// let mut solver = Concurrent(Solution);
+/// let intermediate = solver.parse("1,2,3,4").unwrap();
+/// let both = solver.solve_both(intermediate.as_ref(), &|_, _| {});
+///
+/// assert!(both.concurrent);
+/// assert_eq!(aoc::Answer::Integer(10), both.part_one);
+/// assert_eq!(aoc::Answer::Integer(24), both.part_two);
+/// ```
+pub struct Concurrent<T>(pub T);
+
+impl<T> PartSolve for Concurrent<T>
+where
+	T: TypedPartSolve + Sync,
+	T::Intermediate: Sync,
+{
+	fn parse(&mut self, input: &str) -> anyhow::Result<Box<dyn Any>> {
+		Ok(Box::new(TypedPartSolve::parse(&mut self.0, input)?))
+	}
+
+	fn part_one(&self, intermediate: &dyn Any) -> Answer {
+		TypedPartSolve::part_one(&self.0, downcast_intermediate::<T>(intermediate)).into()
+	}
+
+	fn part_two(&self, intermediate: &dyn Any) -> Answer {
+		TypedPartSolve::part_two(&self.0, downcast_intermediate::<T>(intermediate)).into()
+	}
+
+	fn solve_both(&self, intermediate: &dyn Any, on_solved: OnSolved) -> BothAnswers {
+		let intermediate = downcast_intermediate::<T>(intermediate);
+
+		// Each part is timed on the thread that solves it, so the times add up to more than the time taken overall.
+		let ((part_one, part_one_time), (part_two, part_two_time)) = rayon::join(
+			|| {
+				let (answer, time) =
+					timed(|| Answer::from(TypedPartSolve::part_one(&self.0, intermediate)));
+				on_solved(Part::One, &answer);
+				(answer, time)
+			},
+			|| {
+				let (answer, time) =
+					timed(|| Answer::from(TypedPartSolve::part_two(&self.0, intermediate)));
+				on_solved(Part::Two, &answer);
+				(answer, time)
+			},
+		);
+
+		BothAnswers {
+			part_one,
+			part_one_time,
+			part_two,
+			part_two_time,
+			concurrent: true,
+		}
+	}
+
+	fn params(&self) -> Vec<Param> {
+		TypedPartSolve::params(&self.0)
+	}
+
+	fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+		TypedPartSolve::set_param(&mut self.0, name, value)
+	}
+}
+
+/// Recover the typed intermediate handed back by the blanket `PartSolve::parse`.
+///
+/// Anything else is a bug in the caller, so it panics rather than quietly reporting no answer.
+fn downcast_intermediate<T: TypedPartSolve>(intermediate: &dyn Any) -> &T::Intermediate {
+	intermediate.downcast_ref().unwrap_or_else(|| {
+		panic!(
+			"intermediate passed to {} is not of type {}",
+			core::any::type_name::<T>(),
+			core::any::type_name::<T::Intermediate>()
+		)
+	})
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+	/// A numeric answer.
+	Integer(i128),
+	/// A textual answer, such as a password.
+	Text(String),
+	/// A picture drawn with `#` (lit) and `.` (unlit) cells, one row per line, which usually spells out the answer.
+	Grid(String),
+	/// The part has not been implemented (yet).
+	NotImplemented,
+	/// The part failed to compute an answer.
+	Error(String),
+}
+
+impl Answer {
+	/// The answer as it is recorded, verified and reported, or `None` if there isn't one.
+	///
+	/// Grids are read with [`ocr::read`](crate::util::ocr::read) where possible, and are otherwise reported as drawn.
+	pub fn text(&self) -> Option<String> {
+		match self {
+			Self::Integer(value) => Some(value.to_string()),
+			Self::Text(text) => Some(text.clone()),
+			Self::Grid(grid) => Some(crate::util::ocr::read(grid).unwrap_or_else(|| grid.clone())),
+			Self::NotImplemented | Self::Error(_) => None,
+		}
+	}
+
+	/// Returns true if the part produced an answer.
+	pub fn is_answered(&self) -> bool {
+		!matches!(self, Self::NotImplemented | Self::Error(_))
+	}
+
+	/// A short, machine-readable name for this kind of answer.
+	pub fn kind(&self) -> &'static str {
+		match self {
+			Self::Integer(_) => "integer",
+			Self::Text(_) => "text",
+			Self::Grid(_) => "grid",
+			Self::NotImplemented => "not_implemented",
+			Self::Error(_) => "error",
+		}
+	}
+}
+
+impl core::fmt::Display for Answer {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Integer(value) => write!(f, "{value}"),
+			Self::Text(text) => write!(f, "{text}"),
+			Self::Grid(grid) => match crate::util::ocr::read(grid) {
+				Some(letters) => write!(f, "{letters}"),
+				None => write!(f, "\n{}", grid.trim_end()),
+			},
+			Self::NotImplemented => write!(f, "(not implemented)"),
+			Self::Error(message) => write!(f, "(error: {message})"),
+		}
+	}
+}
+
+macro_rules! answer_from_integer {
+	($($int:ty),+) => {
+		$(
+			impl From<$int> for Answer {
+				fn from(value: $int) -> Self {
+					// Only 128-bit values can fall outside of an i128; they are kept intact as text.
+					i128::try_from(value).map_or_else(|_| Self::Text(value.to_string()), Self::Integer)
+				}
+			}
+		)+
+	};
+}
+
+answer_from_integer!(
+	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl From<f64> for Answer {
+	fn from(value: f64) -> Self {
+		// Integral values (e.g. distances computed with floating-point math) are integer answers.
+		if value.fract() == 0.0 && value.abs() < 2_f64.powi(100) {
+			#[allow(
+				clippy::cast_possible_truncation,
+				reason = "integral and well within range"
+			)]
+			Self::Integer(value as i128)
+		} else {
+			Self::Text(value.to_string())
+		}
+	}
+}
+
+impl From<String> for Answer {
+	fn from(value: String) -> Self {
+		Self::Text(value)
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(value: &str) -> Self {
+		Self::Text(value.to_string())
+	}
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+	fn from(value: Option<T>) -> Self {
+		value.map_or(Self::NotImplemented, Into::into)
+	}
+}
+
+impl<T: Into<Answer>, E: core::fmt::Display> From<Result<T, E>> for Answer {
+	fn from(value: Result<T, E>) -> Self {
+		value.map_or_else(|error| Self::Error(error.to_string()), Into::into)
+	}
+}
+
+/// Identifies one of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+	One,
+	Two,
+}
+
+impl Part {
+	pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+	/// The part's number, as used in machine-readable output.
+	pub fn number(self) -> u8 {
+		match self {
+			Self::One => 1,
+			Self::Two => 2,
+		}
+	}
+}
+
+impl core::fmt::Display for Part {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::One => write!(f, "Part One"),
+			Self::Two => write!(f, "Part Two"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Answer, PartSolve, TypedPartSolve};
+
+	struct Lengths;
+
+	impl TypedPartSolve for Lengths {
+		type Intermediate = Vec<usize>;
+		type PartOne = usize;
+		type PartTwo = String;
+
+		fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+			Ok(input.lines().map(str::len).collect())
+		}
+
+		fn part_one(&self, lengths: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+			Ok(lengths.iter().max().copied())
+		}
+
+		fn part_two(&self, _lengths: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+			Ok(None)
+		}
+	}
+
+	#[test]
+	fn adapter_renders_answers() {
+		let mut solver = Lengths;
+		let intermediate = PartSolve::parse(&mut solver, "a\nabc\nab").unwrap();
+
+		assert_eq!(
+			Answer::Integer(3),
+			PartSolve::part_one(&solver, intermediate.as_ref())
+		);
+		assert_eq!(
+			Answer::NotImplemented,
+			PartSolve::part_two(&solver, intermediate.as_ref())
+		);
+	}
+
+	/// Part two doubles part one's answer, but can only work it out from scratch if handed nothing.
+	struct Doubled;
+
+	impl TypedPartSolve for Doubled {
+		type Intermediate = u32;
+		type PartOne = u32;
+		type PartTwo = u32;
+
+		fn parse(&mut self, input: &str) -> anyhow::Result<Self::Intermediate> {
+			Ok(input.parse()?)
+		}
+
+		fn part_one(&self, value: &Self::Intermediate) -> anyhow::Result<Option<Self::PartOne>> {
+			if *value == 0 {
+				anyhow::bail!("nothing to double");
+			}
+
+			Ok(Some(*value))
+		}
+
+		fn part_two(&self, _value: &Self::Intermediate) -> anyhow::Result<Option<Self::PartTwo>> {
+			Ok(None)
+		}
+
+		fn part_two_given(
+			&self,
+			_value: &Self::Intermediate,
+			part_one: &Self::PartOne,
+		) -> anyhow::Result<Option<Self::PartTwo>> {
+			Ok(Some(part_one * 2))
+		}
+	}
+
+	#[test]
+	fn solve_both_hands_over_part_one() {
+		let mut solver = Doubled;
+		let intermediate = PartSolve::parse(&mut solver, "21").unwrap();
+		let both = solver.solve_both(intermediate.as_ref(), &|_, _| {});
+
+		assert_eq!(Answer::Integer(21), both.part_one);
+		assert_eq!(Answer::Integer(42), both.part_two);
+		assert_eq!(
+			Answer::NotImplemented,
+			PartSolve::part_two(&solver, intermediate.as_ref())
+		);
+	}
+
+	#[test]
+	fn solve_both_reports_errors() {
+		let mut solver = Doubled;
+		let intermediate = PartSolve::parse(&mut solver, "0").unwrap();
+		let both = solver.solve_both(intermediate.as_ref(), &|_, _| {});
+
+		assert_eq!(
+			Answer::Error("nothing to double".to_string()),
+			both.part_one
+		);
+		assert_eq!(Answer::NotImplemented, both.part_two);
+	}
+
+	#[test]
+	#[should_panic(expected = "intermediate passed to")]
+	fn adapter_rejects_foreign_intermediate() {
+		let _ = PartSolve::part_one(&Lengths, &42_u32);
+	}
+
+	#[test]
+	fn answer_conversions() {
+		assert_eq!(Answer::Integer(-3), Answer::from(-3_i32));
+		assert_eq!(Answer::Integer(25), Answer::from(25.0_f64));
+		assert_eq!(Answer::Text("2.5".to_string()), Answer::from(2.5_f64));
+		assert_eq!(Answer::Text(u128::MAX.to_string()), Answer::from(u128::MAX));
+		assert_eq!(Answer::NotImplemented, Answer::from(None::<u32>));
+		assert_eq!(
+			Answer::Error("bad".to_string()),
+			Answer::from(Err::<u32, _>("bad"))
+		);
+	}
+
+	#[test]
+	fn answer_text() {
+		assert_eq!(Some("12".to_string()), Answer::Integer(12).text());
+		assert_eq!(None, Answer::NotImplemented.text());
+		assert_eq!(None, Answer::Error("bad".to_string()).text());
+		assert_eq!(
+			Some("#.\n.#".to_string()),
+			Answer::Grid("#.\n.#".to_string()).text()
+		);
+	}
 }
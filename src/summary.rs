@@ -0,0 +1,371 @@
+use core::time::Duration;
+use std::{collections::BTreeMap, io::Write};
+
+use nu_ansi_term::{Color, Style};
+
+use crate::{Answer, answers::Verdict, report::Record, runner::SolverOutcome};
+
+/// How many of the slowest solvers the summary lists.
+pub const SLOWEST: usize = 5;
+
+/// How a single solver fared over the course of a run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolverStatus {
+	/// Every selected part answered, and no answer contradicted a recorded one.
+	Solved,
+	/// Some, but not all, selected parts answered.
+	Partial,
+	/// No selected part answered.
+	Unsolved,
+	/// The solver printed its own answers, so they are unknown.
+	Unverified,
+	/// The solver errored, panicked, timed out, failed a part, or gave an answer contradicting a recorded one.
+	Failed,
+	/// The solver's input could not be found or read.
+	Skipped,
+}
+
+impl SolverStatus {
+	/// Every status, in the order they're listed in the legend.
+	const ALL: [SolverStatus; 6] = [
+		Self::Solved,
+		Self::Partial,
+		Self::Unsolved,
+		Self::Unverified,
+		Self::Failed,
+		Self::Skipped,
+	];
+
+	/// Classify a solver run from its outcome and the records built from it.
+	pub fn classify(outcome: &SolverOutcome, records: &[Record]) -> Self {
+		match outcome {
+			SolverOutcome::Failed { .. } => Self::Failed,
+			SolverOutcome::Uncaptured => Self::Unverified,
+			SolverOutcome::Captured { .. } => {
+				let answered = records
+					.iter()
+					.filter(|record| record.answer.as_ref().is_some_and(Answer::is_answered))
+					.count();
+
+				if records.iter().any(|record| {
+					matches!(record.verdict, Some(Verdict::Fail { .. }))
+						|| matches!(record.answer, Some(Answer::Error(_)))
+				}) {
+					Self::Failed
+				} else if answered == 0 {
+					Self::Unsolved
+				} else if answered < records.len() {
+					Self::Partial
+				} else {
+					Self::Solved
+				}
+			}
+		}
+	}
+
+	/// The two-character cell shown for this status in the grid.
+	fn cell(self) -> &'static str {
+		match self {
+			Self::Solved => "**",
+			Self::Partial => "* ",
+			Self::Unsolved => "..",
+			Self::Unverified => "??",
+			Self::Failed => "!!",
+			Self::Skipped => "--",
+		}
+	}
+
+	fn style(self) -> Style {
+		match self {
+			Self::Solved => Color::Green.bold(),
+			Self::Partial => Color::Yellow.normal(),
+			Self::Unsolved => Style::new().dimmed(),
+			Self::Unverified => Color::Blue.normal(),
+			Self::Failed => Color::Red.bold(),
+			Self::Skipped => Color::Magenta.normal(),
+		}
+	}
+
+	fn description(self) -> &'static str {
+		match self {
+			Self::Solved => "solved",
+			Self::Partial => "partial",
+			Self::Unsolved => "unsolved",
+			Self::Unverified => "unverified",
+			Self::Failed => "failed",
+			Self::Skipped => "skipped (missing input)",
+		}
+	}
+}
+
+/// The result of running a single solver, as summarized at the end of a run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverResult {
+	pub year: u16,
+	pub day: u8,
+	pub status: SolverStatus,
+	/// Total time spent parsing and solving; `None` if the solver's timings are unavailable.
+	pub elapsed: Option<Duration>,
+}
+
+impl SolverResult {
+	pub fn from_outcome(year: u16, day: u8, outcome: &SolverOutcome, records: &[Record]) -> Self {
+		Self {
+			year,
+			day,
+			status: SolverStatus::classify(outcome, records),
+			elapsed: outcome.timings().map(|timings| timings.total),
+		}
+	}
+
+	pub fn skipped(year: u16, day: u8) -> Self {
+		Self {
+			year,
+			day,
+			status: SolverStatus::Skipped,
+			elapsed: None,
+		}
+	}
+}
+
+/// Whether the summary should be colored: only on a terminal, and only if `NO_COLOR` isn't set.
+pub fn use_color(out: &impl std::io::IsTerminal) -> bool {
+	out.is_terminal() && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// Print a years × days grid of solver statuses, followed by the skipped and slowest solvers.
+pub fn print_summary(
+	results: &[SolverResult],
+	color: bool,
+	out: &mut dyn Write,
+) -> std::io::Result<()> {
+	if results.is_empty() {
+		return Ok(());
+	}
+
+	let paint = |style: Style, text: &str| {
+		if color {
+			style.paint(text).to_string()
+		} else {
+			text.to_string()
+		}
+	};
+
+	let mut grid: BTreeMap<u16, [Option<SolverStatus>; 25]> = BTreeMap::new();
+	for result in results {
+		if let Some(cell) = grid
+			.entry(result.year)
+			.or_insert([None; 25])
+			.get_mut(usize::from(result.day).wrapping_sub(1))
+		{
+			*cell = Some(result.status);
+		}
+	}
+
+	writeln!(out)?;
+	write!(out, "    ")?;
+	for day in 1..=25 {
+		write!(out, " {day:>2}")?;
+	}
+	writeln!(out)?;
+
+	for (year, days) in &grid {
+		let row: String = days
+			.iter()
+			.map(|status| match status {
+				Some(status) => format!(" {}", paint(status.style(), status.cell())),
+				None => "   ".to_string(),
+			})
+			.collect();
+
+		writeln!(out, "{year}{}", row.trim_end())?;
+	}
+
+	let legend: Vec<String> = SolverStatus::ALL
+		.iter()
+		.filter_map(|&status| {
+			let count = results
+				.iter()
+				.filter(|result| result.status == status)
+				.count();
+			(count > 0).then(|| {
+				format!(
+					"{} {} {count}",
+					paint(status.style(), status.cell()),
+					status.description()
+				)
+			})
+		})
+		.collect();
+	writeln!(out, "{}", legend.join(", "))?;
+
+	let skipped: Vec<String> = results
+		.iter()
+		.filter(|result| result.status == SolverStatus::Skipped)
+		.map(|result| format!("{}/{}", result.year, result.day))
+		.collect();
+	if !skipped.is_empty() {
+		writeln!(
+			out,
+			"{} {}",
+			paint(SolverStatus::Skipped.style(), "Skipped (missing input):"),
+			skipped.join(", ")
+		)?;
+	}
+
+	let mut timed: Vec<(&SolverResult, Duration)> = results
+		.iter()
+		.filter_map(|result| Some((result, result.elapsed?)))
+		.collect();
+	timed.sort_by_key(|&(_, elapsed)| core::cmp::Reverse(elapsed));
+
+	if !timed.is_empty() {
+		writeln!(out, "Slowest solvers:")?;
+		for (result, elapsed) in timed.into_iter().take(SLOWEST) {
+			writeln!(out, "  {}/{:<2} {elapsed:>10.1?}", result.year, result.day)?;
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use core::time::Duration;
+
+	use super::{SolverResult, SolverStatus, print_summary};
+	use crate::{
+		Answer, Part,
+		answers::Verdict,
+		report::Record,
+		runner::{Failure, SolverOutcome, Timings},
+	};
+
+	fn record(part: Part, answer: Option<Answer>, verdict: Option<Verdict>) -> Record {
+		Record {
+			year: 2024,
+			day: 1,
+			part,
+			answer,
+			verdict,
+			failure: None,
+			parse_time: None,
+			part_time: None,
+		}
+	}
+
+	fn captured() -> SolverOutcome {
+		SolverOutcome::Captured {
+			part_one: None,
+			part_two: None,
+			timings: Timings {
+				parse: Duration::from_millis(1),
+				part_one: Some(Duration::from_millis(2)),
+				part_two: None,
+				total: Duration::from_millis(3),
+				concurrent: false,
+			},
+		}
+	}
+
+	#[test]
+	fn classify() {
+		let both = [
+			record(Part::One, Some(Answer::Integer(1)), Some(Verdict::Pass)),
+			record(Part::Two, Some(Answer::Integer(2)), Some(Verdict::New)),
+		];
+		let one = [
+			record(Part::One, Some(Answer::Integer(1)), None),
+			record(Part::Two, Some(Answer::NotImplemented), None),
+		];
+		let wrong = [record(
+			Part::One,
+			Some(Answer::Integer(1)),
+			Some(Verdict::Fail {
+				expected: "2".to_string(),
+			}),
+		)];
+		let none = [record(Part::One, Some(Answer::NotImplemented), None)];
+		let errored = [record(
+			Part::One,
+			Some(Answer::Error("bad input".to_string())),
+			None,
+		)];
+
+		assert_eq!(
+			SolverStatus::Solved,
+			SolverStatus::classify(&captured(), &both)
+		);
+		assert_eq!(
+			SolverStatus::Partial,
+			SolverStatus::classify(&captured(), &one)
+		);
+		assert_eq!(
+			SolverStatus::Failed,
+			SolverStatus::classify(&captured(), &wrong)
+		);
+		assert_eq!(
+			SolverStatus::Unsolved,
+			SolverStatus::classify(&captured(), &none)
+		);
+		assert_eq!(
+			SolverStatus::Failed,
+			SolverStatus::classify(&captured(), &errored)
+		);
+		assert_eq!(
+			SolverStatus::Failed,
+			SolverStatus::classify(
+				&SolverOutcome::failed(Failure::Panic("oops".to_string())),
+				&none
+			)
+		);
+		assert_eq!(
+			SolverStatus::Unverified,
+			SolverStatus::classify(&SolverOutcome::Uncaptured, &none)
+		);
+	}
+
+	#[test]
+	fn elapsed_is_total_time() {
+		assert_eq!(
+			Some(Duration::from_millis(3)),
+			SolverResult::from_outcome(2024, 1, &captured(), &[]).elapsed
+		);
+	}
+
+	#[test]
+	fn uncolored_summary() {
+		let results = [
+			SolverResult {
+				year: 2024,
+				day: 1,
+				status: SolverStatus::Solved,
+				elapsed: Some(Duration::from_millis(3)),
+			},
+			SolverResult::skipped(2024, 3),
+			SolverResult {
+				year: 2015,
+				day: 25,
+				status: SolverStatus::Unverified,
+				elapsed: None,
+			},
+		];
+
+		let mut out = Vec::new();
+		print_summary(&results, false, &mut out).unwrap();
+		let out = String::from_utf8(out).unwrap();
+		let lines: Vec<&str> = out.lines().collect();
+
+		assert_eq!("", lines[0]);
+		assert!(lines[1].starts_with("      1  2  3"));
+		assert_eq!(format!("2015{} ??", " ".repeat(3 * 24)), lines[2]);
+		assert_eq!("2024 **    --", lines[3]);
+		assert_eq!(
+			"** solved 1, ?? unverified 1, -- skipped (missing input) 1",
+			lines[4]
+		);
+		assert_eq!("Skipped (missing input): 2024/3", lines[5]);
+		assert_eq!("Slowest solvers:", lines[6]);
+		assert_eq!("  2024/1       3.0ms", lines[7]);
+	}
+}
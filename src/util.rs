@@ -1,4 +1,9 @@
+pub mod graph;
+pub mod grid;
+pub mod math;
 pub mod neighbors;
+pub mod ocr;
+pub mod search;
 
 #[derive(PartialEq)]
 #[allow(dead_code)]
@@ -7,24 +12,74 @@ pub enum SolverMode {
 	PartTwo,
 }
 
-/// Generate the solver for a given day.
+/// Generate the solver for a given day from its module's `Intermediate`, `Output`, `parse`, `part_one` and
+/// `part_two` items, as a `PartSolve`-class solver.
+///
+/// Days whose part two builds on part one's answer are marked `chained`, and also provide a `part_two_given` which
+/// takes that answer; see [`TypedPartSolve::part_two_given`](crate::TypedPartSolve::part_two_given).
 #[macro_export]
 macro_rules! generate_solver {
-	($fn_name:ident, =>, $place:path ) => {
-		pub(crate) fn $fn_name(data: &str) -> Result<(), Box<dyn core::error::Error>> {
-			use $place::{Intermediate, parse, part_one, part_two};
+	($fn_name:ident, =>, $place:path) => {
+		pub(crate) fn $fn_name() -> $crate::Solver {
+			use $place::{Intermediate, Output, parse, part_one, part_two};
 
-			let intermediate: Intermediate = parse(data)?;
+			struct Adapter;
 
-			if let Some(part_one) = part_one(&intermediate) {
-				println!("Part One: {}", part_one);
+			impl $crate::TypedPartSolve for Adapter {
+				type Intermediate = Intermediate;
+				type PartOne = Output;
+				type PartTwo = Output;
+
+				fn parse(&mut self, input: &str) -> anyhow::Result<Intermediate> {
+					parse(input).map_err(Into::into)
+				}
+
+				fn part_one(&self, intermediate: &Intermediate) -> anyhow::Result<Option<Output>> {
+					Ok(part_one(intermediate))
+				}
+
+				fn part_two(&self, intermediate: &Intermediate) -> anyhow::Result<Option<Output>> {
+					Ok(part_two(intermediate))
+				}
 			}
 
-			if let Some(part_two) = part_two(&intermediate) {
-				println!("Part Two: {}", part_two);
+			$crate::Solver::PartSolve(Box::new(Adapter))
+		}
+	};
+
+	($fn_name:ident, =>, $place:path, chained) => {
+		pub(crate) fn $fn_name() -> $crate::Solver {
+			use $place::{Intermediate, Output, parse, part_one, part_two, part_two_given};
+
+			struct Adapter;
+
+			impl $crate::TypedPartSolve for Adapter {
+				type Intermediate = Intermediate;
+				type PartOne = Output;
+				type PartTwo = Output;
+
+				fn parse(&mut self, input: &str) -> anyhow::Result<Intermediate> {
+					parse(input).map_err(Into::into)
+				}
+
+				fn part_one(&self, intermediate: &Intermediate) -> anyhow::Result<Option<Output>> {
+					Ok(part_one(intermediate))
+				}
+
+				fn part_two(&self, intermediate: &Intermediate) -> anyhow::Result<Option<Output>> {
+					Ok(part_two(intermediate))
+				}
+
+				fn part_two_given(
+					&self,
+					intermediate: &Intermediate,
+					part_one: &Output,
+				) -> anyhow::Result<Option<Output>> {
+					Ok(part_two_given(intermediate, part_one))
+				}
 			}
 
-			Ok(())
+			$crate::Solver::PartSolve(Box::new(Adapter))
 		}
 	};
 }
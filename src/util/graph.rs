@@ -0,0 +1,557 @@
+//! Graphs over arbitrary node ids, for the puzzles whose input is a list of connections or ordering rules.
+//!
+//! Nodes and their edges are kept sorted, so everything computed from a graph comes out in the same order every run.
+
+use core::fmt::{Display, Write};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A graph, stored as each node's set of neighbors. Edges are one-way unless the graph is undirected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<N> {
+	directed: bool,
+	edges: BTreeMap<N, BTreeSet<N>>,
+}
+
+/// A cycle found in a graph which was expected to have none, listing each node on it once, in order.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("the graph has a cycle through {0:?}")]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: Ord + Clone> Graph<N> {
+	pub fn directed() -> Self {
+		Self {
+			directed: true,
+			edges: BTreeMap::new(),
+		}
+	}
+
+	pub fn undirected() -> Self {
+		Self {
+			directed: false,
+			edges: BTreeMap::new(),
+		}
+	}
+
+	pub fn is_directed(&self) -> bool {
+		self.directed
+	}
+
+	pub fn add_node(&mut self, node: N) {
+		self.edges.entry(node).or_default();
+	}
+
+	/// Add an edge from `from` to `to`, and back again if the graph is undirected, adding either node if it's new.
+	pub fn add_edge(&mut self, from: N, to: N) {
+		self.add_node(to.clone());
+		if !self.directed {
+			self
+				.edges
+				.entry(to.clone())
+				.or_default()
+				.insert(from.clone());
+		}
+		self.edges.entry(from).or_default().insert(to);
+	}
+
+	pub fn contains_node(&self, node: &N) -> bool {
+		self.edges.contains_key(node)
+	}
+
+	pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+		self
+			.edges
+			.get(from)
+			.is_some_and(|neighbors| neighbors.contains(to))
+	}
+
+	pub fn nodes(&self) -> impl Iterator<Item = &N> {
+		self.edges.keys()
+	}
+
+	/// The nodes an edge leads to from `node`.
+	pub fn neighbors(&self, node: &N) -> impl Iterator<Item = &N> {
+		self.edges.get(node).into_iter().flatten()
+	}
+
+	pub fn node_count(&self) -> usize {
+		self.edges.len()
+	}
+
+	/// The number of edges, counting an undirected edge once.
+	pub fn edge_count(&self) -> usize {
+		let ends: usize = self.edges.values().map(BTreeSet::len).sum();
+		if self.directed {
+			ends
+		} else {
+			let loops = self
+				.edges
+				.iter()
+				.filter(|(node, neighbors)| neighbors.contains(node))
+				.count();
+			// Every other edge has both of its ends counted.
+			(ends - loops) / 2 + loops
+		}
+	}
+
+	/// The graph with only the nodes `keep` accepts, and the edges between them.
+	#[must_use]
+	pub fn subgraph(&self, mut keep: impl FnMut(&N) -> bool) -> Self {
+		let kept: BTreeSet<&N> = self.nodes().filter(|&node| keep(node)).collect();
+
+		Self {
+			directed: self.directed,
+			edges: kept
+				.iter()
+				.map(|&node| {
+					let neighbors = self
+						.neighbors(node)
+						.filter(|neighbor| kept.contains(neighbor))
+						.cloned()
+						.collect();
+					(node.clone(), neighbors)
+				})
+				.collect(),
+		}
+	}
+
+	/// The nodes ordered so that every edge leads forwards, taking the least node first whenever there's a choice, or
+	/// a cycle which makes that impossible.
+	pub fn topological_sort(&self) -> Result<Vec<N>, Cycle<N>> {
+		let indexed = Indexed::new(self);
+		let mut incoming = vec![0_usize; indexed.nodes.len()];
+		for &to in indexed.adjacency.iter().flatten() {
+			incoming[to] += 1;
+		}
+
+		let mut ready: BTreeSet<usize> = (0..incoming.len())
+			.filter(|&node| incoming[node] == 0)
+			.collect();
+		let mut order = Vec::with_capacity(incoming.len());
+
+		while let Some(node) = ready.pop_first() {
+			order.push(indexed.nodes[node].clone());
+
+			for &to in &indexed.adjacency[node] {
+				incoming[to] -= 1;
+				if incoming[to] == 0 {
+					ready.insert(to);
+				}
+			}
+		}
+
+		if order.len() == incoming.len() {
+			Ok(order)
+		} else {
+			Err(Cycle(indexed.cycle_among(|node| incoming[node] > 0)))
+		}
+	}
+
+	/// The strongly connected components: the largest sets of nodes which can each reach every other. Each component
+	/// is sorted, and they're listed so that edges between them only lead to components later in the list.
+	pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+		let indexed = Indexed::new(self);
+		let components = indexed.tarjan();
+
+		components
+			.into_iter()
+			.rev()
+			.map(|mut component| {
+				component.sort_unstable();
+				indexed.named(&component)
+			})
+			.collect()
+	}
+
+	/// The connected components, ignoring which way edges lead. Each component is sorted, and they're listed in order
+	/// of their least nodes.
+	pub fn connected_components(&self) -> Vec<Vec<N>> {
+		let indexed = Indexed::new(self).symmetric();
+		let mut seen = vec![false; indexed.nodes.len()];
+		let mut components = Vec::new();
+
+		for start in 0..indexed.nodes.len() {
+			if seen[start] {
+				continue;
+			}
+
+			seen[start] = true;
+			let mut component = vec![start];
+			let mut stack = vec![start];
+			while let Some(node) = stack.pop() {
+				for &neighbor in &indexed.adjacency[node] {
+					if !seen[neighbor] {
+						seen[neighbor] = true;
+						component.push(neighbor);
+						stack.push(neighbor);
+					}
+				}
+			}
+
+			component.sort_unstable();
+			components.push(indexed.named(&component));
+		}
+
+		components
+	}
+
+	/// Every maximal clique, ignoring which way edges lead: each set of nodes which are all connected to one another
+	/// and can't be grown by another. Each clique is sorted, and they're listed in order.
+	pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+		let indexed = Indexed::new(self).symmetric();
+		let neighbors: Vec<BTreeSet<usize>> = indexed
+			.adjacency
+			.iter()
+			.enumerate()
+			.map(|(node, adjacent)| {
+				adjacent
+					.iter()
+					.copied()
+					.filter(|&neighbor| neighbor != node)
+					.collect()
+			})
+			.collect();
+
+		let mut cliques = Vec::new();
+		bron_kerbosch(
+			&neighbors,
+			&mut Vec::new(),
+			(0..neighbors.len()).collect(),
+			BTreeSet::new(),
+			&mut cliques,
+		);
+
+		let mut cliques: Vec<Vec<N>> = cliques
+			.into_iter()
+			.map(|clique| indexed.named(&clique))
+			.collect();
+		cliques.sort_unstable();
+
+		cliques
+	}
+}
+
+impl<N: Ord + Clone + Display> Graph<N> {
+	/// The graph in Graphviz's DOT language.
+	pub fn to_dot(&self) -> String {
+		let (keyword, arrow) = if self.directed {
+			("digraph", "->")
+		} else {
+			("graph", "--")
+		};
+		let quoted = |node: &N| format!("\"{}\"", node.to_string().replace('"', "\\\""));
+
+		let mut dot = format!("{keyword} {{\n");
+		for (from, neighbors) in &self.edges {
+			if neighbors.is_empty() {
+				writeln!(dot, "\t{};", quoted(from)).unwrap();
+			}
+
+			for to in neighbors {
+				if self.directed || from <= to {
+					writeln!(dot, "\t{} {arrow} {};", quoted(from), quoted(to)).unwrap();
+				}
+			}
+		}
+		dot.push_str("}\n");
+
+		dot
+	}
+}
+
+impl<N: Ord + Clone> Extend<(N, N)> for Graph<N> {
+	fn extend<T: IntoIterator<Item = (N, N)>>(&mut self, edges: T) {
+		for (from, to) in edges {
+			self.add_edge(from, to);
+		}
+	}
+}
+
+/// A graph's nodes numbered in order, with its edges between those numbers, which the algorithms work over.
+struct Indexed<'g, N> {
+	nodes: Vec<&'g N>,
+	adjacency: Vec<Vec<usize>>,
+}
+
+impl<'g, N: Ord + Clone> Indexed<'g, N> {
+	fn new(graph: &'g Graph<N>) -> Self {
+		let nodes: Vec<&N> = graph.nodes().collect();
+		let adjacency = graph
+			.edges
+			.values()
+			.map(|neighbors| {
+				neighbors
+					.iter()
+					.map(|neighbor| nodes.binary_search(&neighbor).unwrap())
+					.collect()
+			})
+			.collect();
+
+		Self { nodes, adjacency }
+	}
+
+	/// The same nodes, with every edge leading both ways.
+	fn symmetric(mut self) -> Self {
+		for from in 0..self.adjacency.len() {
+			for index in 0..self.adjacency[from].len() {
+				let to = self.adjacency[from][index];
+				self.adjacency[to].push(from);
+			}
+		}
+
+		for neighbors in &mut self.adjacency {
+			neighbors.sort_unstable();
+			neighbors.dedup();
+		}
+
+		self
+	}
+
+	fn named(&self, nodes: &[usize]) -> Vec<N> {
+		nodes.iter().map(|&node| self.nodes[node].clone()).collect()
+	}
+
+	/// A cycle among the nodes `within` accepts, every one of which must have an edge to it from another of them. The
+	/// cycle starts from its least node.
+	fn cycle_among(&self, within: impl Fn(usize) -> bool) -> Vec<N> {
+		let mut predecessors = vec![Vec::new(); self.nodes.len()];
+		for (from, neighbors) in self.adjacency.iter().enumerate() {
+			for &to in neighbors {
+				predecessors[to].push(from);
+			}
+		}
+
+		let Some(start) = (0..self.nodes.len()).find(|&node| within(node)) else {
+			return Vec::new();
+		};
+
+		// Walk backwards until a node comes round again; the walk from its first visit is the cycle, reversed.
+		let mut walk = vec![start];
+		let mut position = vec![None; self.nodes.len()];
+		position[start] = Some(0);
+		loop {
+			let node = walk[walk.len() - 1];
+			let previous = predecessors[node]
+				.iter()
+				.copied()
+				.find(|&previous| within(previous))
+				.unwrap();
+
+			if let Some(first) = position[previous] {
+				let mut cycle = walk.split_off(first);
+				cycle.reverse();
+				let least = (0..cycle.len()).min_by_key(|&index| cycle[index]).unwrap();
+				cycle.rotate_left(least);
+
+				return self.named(&cycle);
+			}
+
+			position[previous] = Some(walk.len());
+			walk.push(previous);
+		}
+	}
+
+	/// Tarjan's algorithm, without recursion. Components are found in reverse topological order.
+	fn tarjan(&self) -> Vec<Vec<usize>> {
+		let count = self.nodes.len();
+		let mut index = vec![usize::MAX; count];
+		let mut low_link = vec![0; count];
+		let mut on_stack = vec![false; count];
+		let mut stack = Vec::new();
+		let mut components = Vec::new();
+		let mut next_index = 0;
+
+		for root in 0..count {
+			if index[root] != usize::MAX {
+				continue;
+			}
+
+			// Each frame is a node being visited, and how many of its edges have been followed.
+			let mut frames = vec![(root, 0)];
+			index[root] = next_index;
+			low_link[root] = next_index;
+			next_index += 1;
+			stack.push(root);
+			on_stack[root] = true;
+
+			while let Some(&mut (node, ref mut followed)) = frames.last_mut() {
+				if let Some(&next) = self.adjacency[node].get(*followed) {
+					*followed += 1;
+
+					if index[next] == usize::MAX {
+						index[next] = next_index;
+						low_link[next] = next_index;
+						next_index += 1;
+						stack.push(next);
+						on_stack[next] = true;
+						frames.push((next, 0));
+					} else if on_stack[next] {
+						low_link[node] = low_link[node].min(index[next]);
+					}
+
+					continue;
+				}
+
+				frames.pop();
+				if let Some(&(parent, _)) = frames.last() {
+					low_link[parent] = low_link[parent].min(low_link[node]);
+				}
+
+				if low_link[node] == index[node] {
+					let mut component = Vec::new();
+					while let Some(member) = stack.pop() {
+						on_stack[member] = false;
+						component.push(member);
+						if member == node {
+							break;
+						}
+					}
+					components.push(component);
+				}
+			}
+		}
+
+		components
+	}
+}
+
+/// The Bron–Kerbosch algorithm, pivoting on the candidate with the most neighbors among the candidates.
+fn bron_kerbosch(
+	neighbors: &[BTreeSet<usize>],
+	clique: &mut Vec<usize>,
+	mut candidates: BTreeSet<usize>,
+	mut excluded: BTreeSet<usize>,
+	cliques: &mut Vec<Vec<usize>>,
+) {
+	if candidates.is_empty() {
+		if excluded.is_empty() {
+			let mut found = clique.clone();
+			found.sort_unstable();
+			cliques.push(found);
+		}
+		return;
+	}
+
+	let pivot = candidates
+		.union(&excluded)
+		.max_by_key(|&&node| neighbors[node].intersection(&candidates).count())
+		.copied()
+		.unwrap();
+
+	let choices: Vec<usize> = candidates.difference(&neighbors[pivot]).copied().collect();
+	for node in choices {
+		clique.push(node);
+		bron_kerbosch(
+			neighbors,
+			clique,
+			candidates.intersection(&neighbors[node]).copied().collect(),
+			excluded.intersection(&neighbors[node]).copied().collect(),
+			cliques,
+		);
+		clique.pop();
+
+		candidates.remove(&node);
+		excluded.insert(node);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Cycle, Graph};
+
+	fn directed(edges: &[(char, char)]) -> Graph<char> {
+		let mut graph = Graph::directed();
+		graph.extend(edges.iter().copied());
+		graph
+	}
+
+	fn undirected(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+		let mut graph = Graph::undirected();
+		graph.extend(edges.iter().copied());
+		graph
+	}
+
+	#[test]
+	fn stores_edges() {
+		let graph = undirected(&[("a", "b"), ("b", "c"), ("c", "c")]);
+		assert!(graph.contains_edge(&"b", &"a"));
+		assert!(!graph.contains_edge(&"a", &"c"));
+		assert_eq!(vec![&"a", &"c"], graph.neighbors(&"b").collect::<Vec<_>>());
+		assert_eq!((3, 3), (graph.node_count(), graph.edge_count()));
+
+		let graph = directed(&[('a', 'b'), ('b', 'a'), ('b', 'c')]);
+		assert!(!graph.contains_edge(&'c', &'b'));
+		assert_eq!((3, 3), (graph.node_count(), graph.edge_count()));
+		assert_eq!(
+			directed(&[('a', 'b'), ('b', 'a')]),
+			graph.subgraph(|&node| node != 'c')
+		);
+	}
+
+	#[test]
+	fn exports_dot() {
+		let mut graph = undirected(&[("start", "A"), ("A", "end")]);
+		graph.add_node("lonely");
+		assert_eq!(
+			"graph {\n\t\"A\" -- \"end\";\n\t\"A\" -- \"start\";\n\t\"lonely\";\n}\n",
+			graph.to_dot()
+		);
+
+		assert_eq!(
+			"digraph {\n\t\"a\" -> \"b\";\n\t\"b\";\n}\n",
+			directed(&[('a', 'b')]).to_dot()
+		);
+	}
+
+	#[test]
+	fn sorts_topologically() {
+		let graph = directed(&[('d', 'a'), ('c', 'b'), ('a', 'b'), ('c', 'e')]);
+		assert_eq!(Ok(vec!['c', 'd', 'a', 'b', 'e']), graph.topological_sort());
+
+		let cyclic = directed(&[('a', 'b'), ('b', 'c'), ('c', 'd'), ('d', 'b'), ('d', 'e')]);
+		assert_eq!(Err(Cycle(vec!['b', 'c', 'd'])), cyclic.topological_sort());
+		assert_eq!(
+			"the graph has a cycle through ['b', 'c', 'd']",
+			cyclic.topological_sort().unwrap_err().to_string()
+		);
+	}
+
+	#[test]
+	fn finds_components() {
+		let graph = directed(&[
+			('a', 'b'),
+			('b', 'c'),
+			('c', 'a'),
+			('c', 'd'),
+			('d', 'e'),
+			('e', 'd'),
+			('f', 'g'),
+		]);
+
+		assert_eq!(
+			vec![vec!['f'], vec!['g'], vec!['a', 'b', 'c'], vec!['d', 'e']],
+			graph.strongly_connected_components()
+		);
+		assert_eq!(
+			vec![vec!['a', 'b', 'c', 'd', 'e'], vec!['f', 'g']],
+			graph.connected_components()
+		);
+	}
+
+	#[test]
+	fn finds_maximal_cliques() {
+		let graph = undirected(&[
+			("kh", "tc"),
+			("kh", "qp"),
+			("tc", "qp"),
+			("qp", "ub"),
+			("ub", "kh"),
+			("tc", "ub"),
+			("ta", "co"),
+		]);
+
+		assert_eq!(
+			vec![vec!["co", "ta"], vec!["kh", "qp", "tc", "ub"]],
+			graph.maximal_cliques()
+		);
+	}
+}
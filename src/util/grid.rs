@@ -0,0 +1,524 @@
+//! A rectangular grid of cells, the shape most puzzles' maps take.
+//!
+//! Positions are `(x, y)`, with `x` growing rightwards and `y` growing downwards from the top-left cell, as they're
+//! read from the input. Steps between positions are `(dx, dy)`.
+
+use core::ops::{Index, IndexMut};
+
+/// A cell's position, as `(x, y)`.
+pub type Position = (usize, usize);
+
+/// A step from one position to another, as `(dx, dy)`.
+pub type Step = (isize, isize);
+
+/// The steps to the four orthogonal neighbors, clockwise from up.
+pub const ORTHOGONAL: [Step; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to all eight neighbors, clockwise from up.
+pub const ALL_DIRECTIONS: [Step; 8] = [
+	(0, -1),
+	(1, -1),
+	(1, 0),
+	(1, 1),
+	(0, 1),
+	(-1, 1),
+	(-1, 0),
+	(-1, -1),
+];
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum GridError {
+	#[error("row {row} has {found} cells, but the rows above it have {expected}")]
+	Ragged {
+		row: usize,
+		expected: usize,
+		found: usize,
+	},
+	#[error("unexpected {found:?} at ({x}, {y})")]
+	UnexpectedCell { found: char, x: usize, y: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	width: usize,
+	height: usize,
+	/// The cells, row by row.
+	cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+	/// A grid with every cell set to `fill`.
+	pub fn new(width: usize, height: usize, fill: T) -> Self
+	where
+		T: Clone,
+	{
+		Self {
+			width,
+			height,
+			cells: vec![fill; width * height],
+		}
+	}
+
+	/// A grid with each cell set to `cell` of its position.
+	pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+		Self {
+			width,
+			height,
+			cells: (0..height)
+				.flat_map(|y| (0..width).map(move |x| (x, y)))
+				.map(&mut cell)
+				.collect(),
+		}
+	}
+
+	/// Parse a map with one character per cell and one line per row, mapping each character to a cell with `cell`.
+	///
+	/// Blank lines around the map are ignored. Fails if the rows differ in length, or `cell` returns `None`.
+	pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+		let mut width = None;
+		let mut height = 0;
+		let mut cells = Vec::new();
+
+		for (y, line) in input.trim_matches(['\n', '\r']).lines().enumerate() {
+			let before = cells.len();
+
+			for (x, found) in line.chars().enumerate() {
+				cells.push(cell(found).ok_or(GridError::UnexpectedCell { found, x, y })?);
+			}
+
+			let found = cells.len() - before;
+			match width {
+				Some(expected) if expected != found => {
+					return Err(GridError::Ragged {
+						row: y,
+						expected,
+						found,
+					});
+				}
+				_ => width = Some(found),
+			}
+
+			height += 1;
+		}
+
+		Ok(Self {
+			width: width.unwrap_or_default(),
+			height,
+			cells,
+		})
+	}
+
+	pub const fn width(&self) -> usize {
+		self.width
+	}
+
+	pub const fn height(&self) -> usize {
+		self.height
+	}
+
+	pub const fn contains(&self, (x, y): Position) -> bool {
+		x < self.width && y < self.height
+	}
+
+	pub fn get(&self, position: Position) -> Option<&T> {
+		self
+			.contains(position)
+			.then(|| &self.cells[self.index_of(position)])
+	}
+
+	pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+		self.contains(position).then(|| {
+			let index = self.index_of(position);
+			&mut self.cells[index]
+		})
+	}
+
+	const fn index_of(&self, (x, y): Position) -> usize {
+		y * self.width + x
+	}
+
+	/// The position at signed coordinates `(x, y)`, if it's in the grid.
+	pub fn checked(&self, (x, y): Step) -> Option<Position> {
+		let position = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+		self.contains(position).then_some(position)
+	}
+
+	/// The position at signed coordinates `(x, y)`, wrapping around the edges of the grid.
+	///
+	/// # Panics
+	///
+	/// If the grid is empty.
+	pub fn wrapping(&self, (x, y): Step) -> Position {
+		let wrap = |coordinate: isize, size: usize| {
+			let size = isize::try_from(size).expect("grid is too large");
+			usize::try_from(coordinate.rem_euclid(size)).expect("a remainder is never negative")
+		};
+
+		(wrap(x, self.width), wrap(y, self.height))
+	}
+
+	/// The position one `step` from `position`, if it's in the grid.
+	pub fn step(&self, (x, y): Position, (dx, dy): Step) -> Option<Position> {
+		let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+		self.contains(position).then_some(position)
+	}
+
+	/// The position one `step` from `position`, wrapping around the edges of the grid.
+	pub fn wrapping_step(&self, (x, y): Position, (dx, dy): Step) -> Position {
+		let signed = |coordinate: usize| isize::try_from(coordinate).expect("grid is too large");
+		self.wrapping((signed(x) + dx, signed(y) + dy))
+	}
+
+	/// The orthogonal neighbors of `position` which are in the grid.
+	pub fn neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+		ORTHOGONAL
+			.into_iter()
+			.filter_map(move |step| self.step(position, step))
+	}
+
+	/// The orthogonal and diagonal neighbors of `position` which are in the grid.
+	pub fn all_neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+		ALL_DIRECTIONS
+			.into_iter()
+			.filter_map(move |step| self.step(position, step))
+	}
+
+	/// The positions reached by repeatedly taking `step` from `position`, up to the edge of the grid. `position`
+	/// itself is not included.
+	pub fn ray(&self, position: Position, step: Step) -> impl Iterator<Item = Position> + '_ {
+		core::iter::successors(self.step(position, step), move |&position| {
+			self.step(position, step)
+		})
+	}
+
+	/// Every position in the grid, row by row.
+	pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+		let width = self.width;
+		(0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+	}
+
+	/// Every cell in the grid with its position, row by row.
+	pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+		self.positions().zip(&self.cells)
+	}
+
+	/// The cells of row `y`, from left to right.
+	///
+	/// # Panics
+	///
+	/// If `y` is outside the grid.
+	pub fn row(&self, y: usize) -> &[T] {
+		assert!(
+			y < self.height,
+			"row {y} is outside the {}x{} grid",
+			self.width,
+			self.height
+		);
+
+		&self.cells[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+		(0..self.height).map(|y| self.row(y))
+	}
+
+	/// The cells of column `x`, from top to bottom.
+	///
+	/// # Panics
+	///
+	/// If `x` is outside the grid.
+	pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+		assert!(
+			x < self.width,
+			"column {x} is outside the {}x{} grid",
+			self.width,
+			self.height
+		);
+
+		self.cells.iter().skip(x).step_by(self.width)
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		(0..self.width).map(|x| self.column(x))
+	}
+
+	/// Every diagonal running down and to the right, starting from the bottom-left corner.
+	pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		let starts = (0..self.height)
+			.rev()
+			.map(|y| (0, y))
+			.chain((1..self.width).map(|x| (x, 0)));
+
+		// A grid with no rows or no columns has no diagonals, though it may still have starts along the other edge.
+		starts
+			.filter(|&start| self.contains(start))
+			.map(|start| self.line(start, (1, 1)))
+	}
+
+	/// Every diagonal running down and to the left, starting from the top-left corner.
+	pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		let starts = (0..self.width)
+			.map(|x| (x, 0))
+			.chain((1..self.height).map(|y| (self.width.saturating_sub(1), y)));
+
+		starts
+			.filter(|&start| self.contains(start))
+			.map(|start| self.line(start, (-1, 1)))
+	}
+
+	/// The cells from `start` onwards in the direction of `step`.
+	fn line(&self, start: Position, step: Step) -> impl Iterator<Item = &T> {
+		core::iter::once(start)
+			.chain(self.ray(start, step))
+			.map(|position| &self[position])
+	}
+
+	/// The positions of every cell matching `predicate`, row by row.
+	pub fn find_all(&self, mut predicate: impl FnMut(&T) -> bool) -> impl Iterator<Item = Position> {
+		self
+			.iter()
+			.filter_map(move |(position, cell)| predicate(cell).then_some(position))
+	}
+
+	/// The position of the first cell matching `predicate`, row by row.
+	pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+		self.find_all(predicate).next()
+	}
+
+	/// A grid of the same size, with each cell mapped through `cell`.
+	pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid {
+			width: self.width,
+			height: self.height,
+			cells: self.cells.iter().map(cell).collect(),
+		}
+	}
+
+	/// Draw the grid as text, one line per row, with each cell drawn as `cell` of it.
+	pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+		let mut rendered = String::with_capacity((self.width + 1) * self.height);
+
+		for row in self.rows() {
+			rendered.extend(row.iter().map(&mut cell));
+			rendered.push('\n');
+		}
+
+		rendered
+	}
+}
+
+impl<T> Index<Position> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, position: Position) -> &T {
+		self.get(position).unwrap_or_else(|| {
+			panic!(
+				"{position:?} is outside the {}x{} grid",
+				self.width, self.height
+			)
+		})
+	}
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+	fn index_mut(&mut self, position: Position) -> &mut T {
+		let (width, height) = (self.width, self.height);
+		self
+			.get_mut(position)
+			.unwrap_or_else(|| panic!("{position:?} is outside the {width}x{height} grid"))
+	}
+}
+
+impl core::str::FromStr for Grid<char> {
+	type Err = GridError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse(s, Some)
+	}
+}
+
+impl core::fmt::Display for Grid<char> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.render(|&cell| cell))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Grid, GridError};
+
+	const MAP: &str = "\
+#..
+.#.
+..@
+#.#
+";
+
+	fn map() -> Grid<char> {
+		MAP.parse().unwrap()
+	}
+
+	#[test]
+	fn parses_and_renders() {
+		let grid = map();
+
+		assert_eq!((3, 4), (grid.width(), grid.height()));
+		assert_eq!('@', grid[(2, 2)]);
+		assert_eq!(None, grid.get((3, 0)));
+		assert_eq!(MAP, grid.to_string());
+
+		let walls = Grid::parse(MAP, |c| Some(c == '#')).unwrap();
+		assert_eq!(
+			MAP.replace('@', "."),
+			walls.render(|&wall| if wall { '#' } else { '.' })
+		);
+	}
+
+	#[test]
+	fn parse_errors() {
+		assert_eq!(
+			Err(GridError::Ragged {
+				row: 1,
+				expected: 3,
+				found: 2
+			}),
+			"...\n..\n".parse::<Grid<char>>()
+		);
+		assert_eq!(
+			Err(GridError::UnexpectedCell {
+				found: '?',
+				x: 1,
+				y: 0
+			}),
+			Grid::parse(".?.", |c| (c == '.').then_some(()))
+		);
+	}
+
+	#[test]
+	fn checked_and_wrapping_coordinates() {
+		let grid = map();
+
+		assert_eq!(Some((2, 3)), grid.checked((2, 3)));
+		assert_eq!(None, grid.checked((-1, 0)));
+		assert_eq!(None, grid.checked((0, 4)));
+		assert_eq!((2, 3), grid.wrapping((-1, -1)));
+		assert_eq!((0, 0), grid.wrapping((3, 4)));
+
+		assert_eq!(Some((1, 0)), grid.step((0, 0), (1, 0)));
+		assert_eq!(None, grid.step((0, 0), (0, -1)));
+		assert_eq!((0, 3), grid.wrapping_step((0, 0), (0, -1)));
+	}
+
+	#[test]
+	fn neighbors_and_rays() {
+		let grid = map();
+
+		assert_eq!(
+			vec![(1, 0), (0, 1)],
+			grid.neighbors((0, 0)).collect::<Vec<_>>()
+		);
+		assert_eq!(8, grid.all_neighbors((1, 1)).count());
+		assert_eq!(3, grid.all_neighbors((0, 0)).count());
+		assert_eq!(
+			vec![(1, 1), (2, 2)],
+			grid.ray((0, 0), (1, 1)).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn rows_columns_and_diagonals() {
+		let grid = map();
+		let text = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+		assert_eq!(
+			vec!["#..", ".#.", "..@", "#.#"],
+			grid
+				.rows()
+				.map(|row| row.iter().collect::<String>())
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec!["#..#", ".#..", "..@#"],
+			grid
+				.columns()
+				.map(|mut column| text(&mut column))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec!["#", "..", "..#", "##@", "..", "."],
+			grid
+				.diagonals()
+				.map(|mut diagonal| text(&mut diagonal))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec!["#", "..", ".#.", "..#", "@.", "#"],
+			grid
+				.anti_diagonals()
+				.map(|mut diagonal| text(&mut diagonal))
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn finds_positions() {
+		let grid = map();
+
+		assert_eq!(
+			vec![(0, 0), (1, 1), (0, 3), (2, 3)],
+			grid.find_all(|&c| c == '#').collect::<Vec<_>>()
+		);
+		assert_eq!(Some((2, 2)), grid.find(|&c| c == '@'));
+		assert_eq!(None, grid.find(|&c| c == '?'));
+	}
+
+	#[test]
+	fn builds_and_maps() {
+		let grid = Grid::from_fn(3, 2, |(x, y)| x + y);
+		assert_eq!(
+			vec![0, 1, 2, 1, 2, 3],
+			grid.iter().map(|(_, &n)| n).collect::<Vec<_>>()
+		);
+
+		let mut grid = grid.map(|&n| n % 2 == 0);
+		grid[(0, 0)] = false;
+		assert_eq!(
+			"..#\n.#.\n",
+			grid.render(|&even| if even { '#' } else { '.' })
+		);
+
+		assert_eq!(Grid::new(2, 2, 'x'), Grid::from_fn(2, 2, |_| 'x'));
+	}
+
+	#[test]
+	fn rejects_positions_outside() {
+		let mut grid = map();
+		assert_eq!(None, grid.get_mut((usize::MAX, usize::MAX)));
+		assert_eq!(None, grid.get_mut((3, 0)));
+		assert_eq!(Some(&mut '@'), grid.get_mut((2, 2)));
+
+		for empty in [Grid::new(0, 3, '.'), Grid::new(3, 0, '.')] {
+			assert_eq!(0, empty.diagonals().count());
+			assert_eq!(0, empty.anti_diagonals().count());
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "is outside the 3x4 grid")]
+	fn index_mut_rejects_overflowing_positions() {
+		let mut grid = map();
+		grid[(usize::MAX, 1)] = '#';
+	}
+
+	#[test]
+	#[should_panic(expected = "row 4 is outside the 3x4 grid")]
+	fn row_rejects_positions_outside() {
+		let _ = map().row(4);
+	}
+
+	#[test]
+	#[should_panic(expected = "column 3 is outside the 3x4 grid")]
+	fn column_rejects_positions_outside() {
+		let _ = map().column(3);
+	}
+}
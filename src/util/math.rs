@@ -0,0 +1,241 @@
+//! Number theory for the puzzles built on modular arithmetic: bus timetables, card shuffles, handshakes.
+//!
+//! Products are taken in a type twice as wide as the arguments, so nothing overflows however large the moduli are.
+
+use std::collections::HashMap;
+
+use num::Signed;
+use num_integer::{Integer, Roots};
+
+/// Compute the greatest common divisor of `a` and `b` using the Extended Euclidean Algorithm, along with `x` and `y`
+/// such that `a * x + b * y` is that divisor.
+///
+/// The coefficients can be negative, so `T` must be a signed type.
+pub fn extended_gcd<T: Copy + Integer + Signed>(a: T, b: T) -> (T, T, T) {
+	let (mut gcd, mut remainder) = (a, b);
+	let (mut a_coefficient, mut next_a) = (T::one(), T::zero());
+	let (mut b_coefficient, mut next_b) = (T::zero(), T::one());
+
+	while remainder != T::zero() {
+		let quotient = gcd / remainder;
+		(gcd, remainder) = (remainder, gcd - quotient * remainder);
+		(a_coefficient, next_a) = (next_a, a_coefficient - quotient * next_a);
+		(b_coefficient, next_b) = (next_b, b_coefficient - quotient * next_b);
+	}
+
+	(gcd, a_coefficient, b_coefficient)
+}
+
+/// Compute the multiplicative inverse of `n` in Z_`modulus`, if one exists.
+pub fn modular_multiplicative_inverse<T: Copy + Integer + Signed>(n: T, modulus: T) -> Option<T> {
+	let (g, x, _) = extended_gcd(n, modulus);
+
+	if g == T::one() {
+		Some(x.mod_floor(&modulus))
+	} else {
+		None
+	}
+}
+
+/// Combine `(modulus, remainder)` congruences into the one congruence, `(modulus, remainder)`, which the numbers
+/// satisfying all of them satisfy, using the Chinese Remainder Theorem.
+///
+/// The moduli needn't be coprime; the combined modulus is their least common multiple. There is no such congruence if
+/// the given ones contradict each other, and `None` is also returned if the combined modulus doesn't fit in an `i64`.
+///
+/// See also the article on [Wikipedia](https://en.wikipedia.org/wiki/Chinese_remainder_theorem).
+///
+/// # Panics
+///
+/// If any modulus is 0 or negative.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+	let mut combined: (i128, i128) = (1, 0);
+
+	for &(modulus, remainder) in congruences {
+		assert!(modulus > 0, "the modulus must be positive");
+
+		let (m1, a1) = combined;
+		let m2 = i128::from(modulus);
+		let a2 = i128::from(remainder).rem_euclid(m2);
+
+		// x = a1 + m1 * t solves both when m1 * t ≡ a2 - a1 (mod m2), which needs gcd(m1, m2) to divide a2 - a1.
+		let (g, m1_inverse, _) = extended_gcd(m1, m2);
+		if (a2 - a1) % g != 0 {
+			return None;
+		}
+
+		let step = m2 / g;
+		let t = ((a2 - a1) / g % step * (m1_inverse % step)).rem_euclid(step);
+		let lcm = m1 * step;
+		if lcm > i128::from(i64::MAX) {
+			return None;
+		}
+
+		combined = (lcm, (a1 + m1 * t).rem_euclid(lcm));
+	}
+
+	Some((
+		i64::try_from(combined.0).ok()?,
+		i64::try_from(combined.1).ok()?,
+	))
+}
+
+fn multiply_mod(a: u64, b: u64, modulus: u64) -> u64 {
+	u64::try_from(u128::from(a) * u128::from(b) % u128::from(modulus)).unwrap()
+}
+
+/// Compute `base` to the power of `exponent`, modulo `modulus`, by repeated squaring.
+///
+/// # Panics
+///
+/// If `modulus` is 0.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+	assert!(modulus > 0, "the modulus must be positive");
+
+	let mut base = base % modulus;
+	let mut result = 1 % modulus;
+
+	while exponent > 0 {
+		if exponent & 1 == 1 {
+			result = multiply_mod(result, base, modulus);
+		}
+		base = multiply_mod(base, base, modulus);
+		exponent >>= 1;
+	}
+
+	result
+}
+
+/// Find the smallest `x` for which `base` to the power of `x` is `target`, modulo `modulus`, using the baby-step
+/// giant-step algorithm.
+///
+/// `base` must be coprime to `modulus`, as it is whenever the modulus is prime. Returns `None` if there's no such `x`.
+///
+/// # Panics
+///
+/// If `modulus` is 0.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+	assert!(modulus > 0, "the modulus must be positive");
+
+	let inverse = modular_multiplicative_inverse(i128::from(base), i128::from(modulus))?;
+	let inverse = u64::try_from(inverse).unwrap();
+
+	// Any x below the modulus is i * steps + j for some i and j below steps.
+	let steps = isqrt(modulus) + 1;
+
+	let mut baby_steps = HashMap::with_capacity(usize::try_from(steps).unwrap());
+	let mut power = 1 % modulus;
+	for j in 0..steps {
+		baby_steps.entry(power).or_insert(j);
+		power = multiply_mod(power, base, modulus);
+	}
+
+	let giant_step = mod_pow(inverse, steps, modulus);
+	let mut remaining = target % modulus;
+	for i in 0..steps {
+		if let Some(&j) = baby_steps.get(&remaining) {
+			return Some(i * steps + j);
+		}
+		remaining = multiply_mod(remaining, giant_step, modulus);
+	}
+
+	None
+}
+
+/// The least common multiple of `values`, or 1 if there are none.
+pub fn lcm<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+	values
+		.into_iter()
+		.fold(T::one(), |lcm, value| lcm.lcm(&value))
+}
+
+/// The integer square root of `n`: the largest integer whose square is at most `n`. `n` mustn't be negative.
+pub fn isqrt<T: Roots + Copy>(n: T) -> T {
+	n.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		crt, discrete_log, extended_gcd, isqrt, lcm, mod_pow, modular_multiplicative_inverse,
+	};
+
+	#[test]
+	fn extended_gcd_and_inverse() {
+		assert_eq!((2, -9, 47), extended_gcd(240, 46));
+		assert_eq!((1, 1, 0), extended_gcd(1, 0));
+		assert_eq!(Some(4), modular_multiplicative_inverse(3, 11));
+		assert_eq!(Some(12), modular_multiplicative_inverse(10, 17));
+		assert_eq!(None, modular_multiplicative_inverse(6, 9));
+	}
+
+	#[test]
+	fn crt_vectors() {
+		assert_eq!(Some((105, 23)), crt(&[(3, 2), (5, 3), (7, 2)]));
+		assert_eq!(Some((12, 8)), crt(&[(6, 2), (4, 0)]));
+		assert_eq!(None, crt(&[(6, 1), (4, 0)]));
+		assert_eq!(Some((1, 0)), crt(&[]));
+
+		// The buses from 2020's day 13 example, each a minute later than the last.
+		assert_eq!(
+			Some((3_162_341, 1_068_781)),
+			crt(&[(7, 0), (13, -1), (59, -4), (31, -6), (19, -7)])
+		);
+
+		assert_eq!(
+			Some((998_244_359_987_710_471, 993_328_913_953_302_350)),
+			crt(&[(1_000_000_007, 1), (998_244_353, 2)])
+		);
+		assert_eq!(None, crt(&[(i64::MAX, 0), (2, 1)]));
+	}
+
+	#[test]
+	#[should_panic(expected = "the modulus must be positive")]
+	fn crt_rejects_a_zero_modulus() {
+		crt(&[(3, 2), (0, 1)]);
+	}
+
+	#[test]
+	#[should_panic(expected = "the modulus must be positive")]
+	fn crt_rejects_a_negative_modulus() {
+		crt(&[(-5, 3)]);
+	}
+
+	#[test]
+	fn mod_pow_vectors() {
+		assert_eq!(24, mod_pow(2, 10, 1000));
+		assert_eq!(5_764_801, mod_pow(7, 8, 20_201_227));
+		assert_eq!(
+			235_787_227_556_774_884,
+			mod_pow(3, 1_000_000_000_000_000_000, 1_000_000_000_000_000_009)
+		);
+		assert_eq!(0, mod_pow(5, 0, 1));
+	}
+
+	#[test]
+	#[should_panic(expected = "the modulus must be positive")]
+	fn mod_pow_rejects_a_zero_modulus() {
+		mod_pow(2, 10, 0);
+	}
+
+	#[test]
+	fn discrete_log_vectors() {
+		assert_eq!(Some(8), discrete_log(7, 5_764_801, 20_201_227));
+		assert_eq!(Some(11), discrete_log(7, 17_807_724, 20_201_227));
+		assert_eq!(Some(4), discrete_log(3, 13, 17));
+		assert_eq!(Some(0), discrete_log(3, 1, 17));
+		assert_eq!(Some(123_456_789), discrete_log(3, 390_341_950, 998_244_353));
+		assert_eq!(None, discrete_log(2, 3, 7));
+		assert_eq!(None, discrete_log(6, 0, 9));
+	}
+
+	#[test]
+	fn lcm_and_isqrt() {
+		assert_eq!(60, lcm([4, 6, 10]));
+		assert_eq!(1, lcm(Vec::<u64>::new()));
+		assert_eq!(0, isqrt(0_u64));
+		assert_eq!(3, isqrt(15_u32));
+		assert_eq!(4, isqrt(16_i64));
+		assert_eq!(4_294_967_295, isqrt(u64::MAX));
+	}
+}
@@ -1,5 +1,13 @@
+//! Neighbor generation for positions in any number of dimensions, over any integer coordinate type.
+//!
+//! Offset tables are built at compile time by [`moore`] and [`von_neumann`], e.g.
+//! `const MOORE_4D: [[i8; 4]; 80] = moore();`, and the common ones are provided as constants. Offsets are listed in
+//! lexicographic order, with the first axis varying slowest.
+
 #![allow(dead_code)]
 
+use core::ops::Range;
+
 const NEIGHBOR_OFFSETS_INCLUDING_DIAGS: [(i32, i32); 8] = [
 	(-1, 1),
 	(0, 1),
@@ -24,3 +32,209 @@ pub fn get_no_diags(pos: &(i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
 		.into_iter()
 		.map(move |offset| (pos.0 + offset.0, pos.1 + offset.1))
 }
+
+pub const MOORE_2D: [[i8; 2]; 8] = moore();
+pub const MOORE_3D: [[i8; 3]; 26] = moore();
+pub const MOORE_4D: [[i8; 4]; 80] = moore();
+
+pub const VON_NEUMANN_2D: [[i8; 2]; 4] = von_neumann();
+pub const VON_NEUMANN_3D: [[i8; 3]; 6] = von_neumann();
+
+/// The six neighbors on a hexagonal grid in cube coordinates, `[q, r, s]` with `q + r + s == 0`.
+pub const HEXAGONAL: [[i8; 3]; 6] = [
+	[1, -1, 0],
+	[1, 0, -1],
+	[0, 1, -1],
+	[-1, 1, 0],
+	[-1, 0, 1],
+	[0, -1, 1],
+];
+
+/// The six neighbors on a hexagonal grid in axial coordinates, `[q, r]`: cube coordinates without `s`.
+pub const HEXAGONAL_AXIAL: [[i8; 2]; 6] = [[1, -1], [1, 0], [0, 1], [-1, 1], [-1, 0], [0, -1]];
+
+/// The offsets to every cell touching a cell in `D` dimensions, diagonals included; `N` must be `3^D - 1`.
+pub const fn moore<const D: usize, const N: usize>() -> [[i8; D]; N] {
+	let mut cells = 1;
+	let mut axis = 0;
+	while axis < D {
+		cells *= 3;
+		axis += 1;
+	}
+	assert!(N + 1 == cells, "a Moore neighborhood has 3^D - 1 offsets");
+
+	let mut offsets = [[0; D]; N];
+	let mut index = 0;
+	let mut cell = 0;
+	while cell < cells {
+		let mut offset = [0; D];
+		let mut is_origin = true;
+		let mut rest = cell;
+		let mut axis = D;
+		while axis > 0 {
+			axis -= 1;
+			offset[axis] = match rest % 3 {
+				0 => -1,
+				1 => 0,
+				_ => 1,
+			};
+			is_origin &= offset[axis] == 0;
+			rest /= 3;
+		}
+
+		if !is_origin {
+			offsets[index] = offset;
+			index += 1;
+		}
+		cell += 1;
+	}
+
+	offsets
+}
+
+/// The offsets to every cell sharing a face with a cell in `D` dimensions; `N` must be `2 * D`.
+pub const fn von_neumann<const D: usize, const N: usize>() -> [[i8; D]; N] {
+	assert!(N == 2 * D, "a von Neumann neighborhood has 2 * D offsets");
+
+	let mut offsets = [[0; D]; N];
+	let mut axis = 0;
+	while axis < D {
+		offsets[axis][axis] = -1;
+		offsets[N - 1 - axis][axis] = 1;
+		axis += 1;
+	}
+
+	offsets
+}
+
+/// An integer type positions can be made of.
+pub trait Coordinate: Copy {
+	/// Move by `by`, or `None` if the result doesn't fit in the type.
+	fn offset(self, by: i8) -> Option<Self>;
+}
+
+macro_rules! impl_coordinate {
+	(signed: $($signed:ty),*; unsigned: $($unsigned:ty),*) => {
+		$(
+			impl Coordinate for $signed {
+				fn offset(self, by: i8) -> Option<Self> {
+					self.checked_add(by.into())
+				}
+			}
+		)*
+
+		$(
+			impl Coordinate for $unsigned {
+				fn offset(self, by: i8) -> Option<Self> {
+					self.checked_add_signed(by.into())
+				}
+			}
+		)*
+	};
+}
+
+impl_coordinate!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+fn shift<T: Coordinate, const D: usize>(position: [T; D], offset: &[i8; D]) -> Option<[T; D]> {
+	let mut shifted = position;
+	for (coordinate, &by) in shifted.iter_mut().zip(offset) {
+		*coordinate = coordinate.offset(by)?;
+	}
+
+	Some(shifted)
+}
+
+/// The neighbors of `position` at each of `offsets`, skipping any which don't fit in `T` (such as those left of 0
+/// for an unsigned coordinate).
+pub fn around<'a, T: Coordinate + 'a, const D: usize>(
+	position: [T; D],
+	offsets: &'a [[i8; D]],
+) -> impl Iterator<Item = [T; D]> + 'a {
+	offsets
+		.iter()
+		.filter_map(move |offset| shift(position, offset))
+}
+
+/// The neighbors of `position` at each of `offsets` which fall within `bounds` along every axis, as for a cell on a
+/// grid of fixed size.
+pub fn bounded<'a, T: Coordinate + PartialOrd + 'a, const D: usize>(
+	position: [T; D],
+	offsets: &'a [[i8; D]],
+	bounds: [Range<T>; D],
+) -> impl Iterator<Item = [T; D]> + 'a {
+	around(position, offsets).filter(move |neighbor| {
+		neighbor
+			.iter()
+			.zip(&bounds)
+			.all(|(coordinate, bounds)| bounds.contains(coordinate))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		HEXAGONAL, MOORE_2D, MOORE_3D, MOORE_4D, VON_NEUMANN_2D, VON_NEUMANN_3D, around, bounded,
+	};
+
+	#[test]
+	fn generates_offset_tables() {
+		assert_eq!(
+			[
+				[-1, -1],
+				[-1, 0],
+				[-1, 1],
+				[0, -1],
+				[0, 1],
+				[1, -1],
+				[1, 0],
+				[1, 1]
+			],
+			MOORE_2D
+		);
+		assert_eq!([[-1, 0], [0, -1], [0, 1], [1, 0]], VON_NEUMANN_2D);
+		assert_eq!(
+			[
+				[-1, 0, 0],
+				[0, -1, 0],
+				[0, 0, -1],
+				[0, 0, 1],
+				[0, 1, 0],
+				[1, 0, 0]
+			],
+			VON_NEUMANN_3D
+		);
+
+		assert!(MOORE_3D.iter().all(|offset| offset != &[0, 0, 0]));
+		assert!(MOORE_4D.windows(2).all(|pair| pair[0] < pair[1]));
+		assert!(
+			HEXAGONAL
+				.iter()
+				.all(|offset| offset.iter().sum::<i8>() == 0)
+		);
+	}
+
+	#[test]
+	fn skips_neighbors_outside_the_type() {
+		assert_eq!(
+			vec![[0, 1], [1, 0]],
+			around([0_u8, 0], &VON_NEUMANN_2D).collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec![[-1, 0], [0, -1], [0, 1], [1, 0]],
+			around([0_i64, 0], &VON_NEUMANN_2D).collect::<Vec<_>>()
+		);
+		assert_eq!(17, around([i8::MAX, 0, 0], &MOORE_3D).count());
+	}
+
+	#[test]
+	fn bounds_neighbors() {
+		assert_eq!(
+			vec![[2, 3], [2, 4], [3, 3], [4, 3], [4, 4]],
+			bounded([3_usize, 4], &MOORE_2D, [0..5, 0..5]).collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec![[1, 2], [2, 1]],
+			bounded([1_i32, 1], &VON_NEUMANN_2D, [1..3, 1..3]).collect::<Vec<_>>()
+		);
+	}
+}
@@ -0,0 +1,110 @@
+//! Reading the block letters some puzzles draw as their answer.
+
+/// The letters of the six-row font, as drawn by the puzzles, trimmed to their lit columns.
+const GLYPHS: &[(char, [&str; 6])] = &[
+	('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+	('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+	('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+	('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+	('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+	('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+	('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+	('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+	('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+	('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+	('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+	('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+	('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+	('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+	('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+	('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+	('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read the letters drawn in `grid`, where `#` and `█` are lit cells and anything else is unlit.
+///
+/// Letters are separated by at least one unlit column. Returns `None` unless the grid is six rows tall (ignoring
+/// blank rows around it) and every letter in it is recognized.
+pub fn read(grid: &str) -> Option<String> {
+	let rows: Vec<Vec<bool>> = grid
+		.lines()
+		.map(|line| line.chars().map(|c| matches!(c, '#' | '█')).collect())
+		.skip_while(|row: &Vec<bool>| !row.contains(&true))
+		.collect();
+	let lit_rows = rows.iter().rposition(|row| row.contains(&true))? + 1;
+	let rows = &rows[..lit_rows];
+
+	if rows.len() != 6 {
+		return None;
+	}
+
+	let width = rows.iter().map(Vec::len).max()?;
+	let column_is_lit = |column: usize| rows.iter().any(|row| row.get(column) == Some(&true));
+
+	let mut letters = String::new();
+	let mut column = 0;
+
+	while column < width {
+		if !column_is_lit(column) {
+			column += 1;
+			continue;
+		}
+
+		let start = column;
+		while column < width && column_is_lit(column) {
+			column += 1;
+		}
+
+		letters.push(recognize(rows, start..column)?);
+	}
+
+	(!letters.is_empty()).then_some(letters)
+}
+
+fn recognize(rows: &[Vec<bool>], columns: core::ops::Range<usize>) -> Option<char> {
+	GLYPHS.iter().find_map(|(letter, glyph)| {
+		let matches = glyph.iter().zip(rows).all(|(glyph_row, row)| {
+			glyph_row.len() == columns.len()
+				&& glyph_row
+					.chars()
+					.zip(columns.clone())
+					.all(|(cell, column)| (cell == '#') == (row.get(column) == Some(&true)))
+		});
+
+		matches.then_some(*letter)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::read;
+
+	#[test]
+	fn reads_letters() {
+		let grid = "\
+#..#.###..#...#
+#..#..#...#...#
+####..#....#.#.
+#..#..#.....#..
+#..#..#.....#..
+#..#.###....#..
+";
+
+		assert_eq!(Some("HIY".to_string()), read(grid));
+	}
+
+	#[test]
+	fn ignores_surrounding_blank_rows_and_columns() {
+		let grid = "\n......\n.####.\n.#....\n.###..\n.#....\n.#....\n.#....\n......\n";
+
+		assert_eq!(Some("F".to_string()), read(grid));
+	}
+
+	#[test]
+	fn rejects_unknown_shapes() {
+		assert_eq!(None, read("#\n#\n#\n"));
+		assert_eq!(None, read("##\n##\n##\n##\n##\n##\n"));
+		assert_eq!(None, read(""));
+	}
+}
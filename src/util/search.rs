@@ -0,0 +1,453 @@
+//! Shortest-path searches over state spaces a puzzle defines through [`Space`].
+//!
+//! A state can be anything hashable: a position, a position and a facing, a whole burrow of amphipods. Each search
+//! starts from one state and stops at the first state its `is_goal` accepts. Costs mustn't be negative.
+
+use core::{cmp::Ordering, hash::Hash, ops::Add};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// A space of states, and the moves between them.
+pub trait Space {
+	type State: Clone + Eq + Hash;
+	type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+	/// The states one move away from `state`, each with the cost of moving there.
+	fn successors(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, Self::Cost)>;
+
+	/// A lower bound on the cost from `state` to a goal, which guides [`astar`]. It must never overestimate.
+	fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+		Self::Cost::default()
+	}
+}
+
+/// The states along a path, from the start to the goal inclusive, and its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+	pub states: Vec<S>,
+	pub cost: C,
+}
+
+/// The best cost found to each state, and the state it was reached from.
+type Visits<S, C> = HashMap<S, (C, Option<S>)>;
+
+/// The states a search visited, and the goal it stopped at if it found one.
+type Explored<S, C> = (Visits<S, C>, Option<S>);
+
+fn reconstruct<S: Clone + Eq + Hash, C>(visits: &Visits<S, C>, goal: S) -> Vec<S> {
+	let mut states = vec![goal];
+	while let Some((_, Some(previous))) = states.last().and_then(|state| visits.get(state)) {
+		states.push(previous.clone());
+	}
+	states.reverse();
+
+	states
+}
+
+/// A state waiting in the priority queue, ordered so the lowest priority is popped first.
+struct Queued<S, C> {
+	priority: C,
+	cost: C,
+	state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+	fn eq(&self, other: &Self) -> bool {
+		self.priority == other.priority
+	}
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		other.priority.cmp(&self.priority)
+	}
+}
+
+fn breadth_first<Sp: Space>(
+	space: &Sp,
+	start: Sp::State,
+	mut is_goal: impl FnMut(&Sp::State) -> bool,
+) -> Explored<Sp::State, usize> {
+	let mut visits = Visits::from([(start.clone(), (0, None))]);
+	let mut queue = VecDeque::from([(start, 0)]);
+
+	while let Some((state, steps)) = queue.pop_front() {
+		if is_goal(&state) {
+			return (visits, Some(state));
+		}
+
+		for (next, _) in space.successors(&state) {
+			if !visits.contains_key(&next) {
+				visits.insert(next.clone(), (steps + 1, Some(state.clone())));
+				queue.push_back((next, steps + 1));
+			}
+		}
+	}
+
+	(visits, None)
+}
+
+fn best_first<Sp: Space>(
+	space: &Sp,
+	start: Sp::State,
+	mut is_goal: impl FnMut(&Sp::State) -> bool,
+	heuristic: impl Fn(&Sp::State) -> Sp::Cost,
+) -> Explored<Sp::State, Sp::Cost> {
+	let mut visits = Visits::from([(start.clone(), (Sp::Cost::default(), None))]);
+	let mut queue = BinaryHeap::from([Queued {
+		priority: heuristic(&start),
+		cost: Sp::Cost::default(),
+		state: start,
+	}]);
+
+	while let Some(Queued { cost, state, .. }) = queue.pop() {
+		// The state may have been queued again since, more cheaply.
+		if visits[&state].0 < cost {
+			continue;
+		}
+
+		if is_goal(&state) {
+			return (visits, Some(state));
+		}
+
+		for (next, step) in space.successors(&state) {
+			let next_cost = cost + step;
+			if visits
+				.get(&next)
+				.is_none_or(|&(known, _)| next_cost < known)
+			{
+				visits.insert(next.clone(), (next_cost, Some(state.clone())));
+				queue.push(Queued {
+					priority: next_cost + heuristic(&next),
+					cost: next_cost,
+					state: next,
+				});
+			}
+		}
+	}
+
+	(visits, None)
+}
+
+/// The path to a goal in the fewest moves, ignoring their costs; the path's cost is its number of moves.
+pub fn bfs<Sp: Space>(
+	space: &Sp,
+	start: Sp::State,
+	is_goal: impl FnMut(&Sp::State) -> bool,
+) -> Option<Path<Sp::State, usize>> {
+	let (visits, goal) = breadth_first(space, start, is_goal);
+	let goal = goal?;
+
+	Some(Path {
+		cost: visits[&goal].0,
+		states: reconstruct(&visits, goal),
+	})
+}
+
+/// The cheapest path to a goal.
+pub fn dijkstra<Sp: Space>(
+	space: &Sp,
+	start: Sp::State,
+	is_goal: impl FnMut(&Sp::State) -> bool,
+) -> Option<Path<Sp::State, Sp::Cost>> {
+	let (visits, goal) = best_first(space, start, is_goal, |_| Sp::Cost::default());
+	let goal = goal?;
+
+	Some(Path {
+		cost: visits[&goal].0,
+		states: reconstruct(&visits, goal),
+	})
+}
+
+/// The cheapest path to a goal, searching towards it as guided by [`Space::heuristic`].
+pub fn astar<Sp: Space>(
+	space: &Sp,
+	start: Sp::State,
+	is_goal: impl FnMut(&Sp::State) -> bool,
+) -> Option<Path<Sp::State, Sp::Cost>> {
+	let (visits, goal) = best_first(space, start, is_goal, |state| space.heuristic(state));
+	let goal = goal?;
+
+	Some(Path {
+		cost: visits[&goal].0,
+		states: reconstruct(&visits, goal),
+	})
+}
+
+/// The fewest moves to every state reachable from `start`.
+pub fn bfs_distances<Sp: Space>(space: &Sp, start: Sp::State) -> HashMap<Sp::State, usize> {
+	let (visits, _) = breadth_first(space, start, |_| false);
+
+	visits
+		.into_iter()
+		.map(|(state, (steps, _))| (state, steps))
+		.collect()
+}
+
+/// The cheapest cost to every state reachable from `start`.
+pub fn distances<Sp: Space>(space: &Sp, start: Sp::State) -> HashMap<Sp::State, Sp::Cost> {
+	let (visits, _) = best_first(space, start, |_| false, |_| Sp::Cost::default());
+
+	visits
+		.into_iter()
+		.map(|(state, (cost, _))| (state, cost))
+		.collect()
+}
+
+/// Every cheapest path from a start to its goals, all of which cost the same.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+	start: S,
+	cost: C,
+	goals: Vec<S>,
+	predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> ShortestPaths<S, C> {
+	pub fn cost(&self) -> C {
+		self.cost
+	}
+
+	/// The goals reached at the cheapest cost.
+	pub fn goals(&self) -> &[S] {
+		&self.goals
+	}
+
+	/// Every state on any of the paths.
+	pub fn states(&self) -> HashSet<S> {
+		let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+		let mut stack = self.goals.clone();
+
+		while let Some(state) = stack.pop() {
+			for previous in self.predecessors.get(&state).into_iter().flatten() {
+				if states.insert(previous.clone()) {
+					stack.push(previous.clone());
+				}
+			}
+		}
+
+		states
+	}
+
+	/// Every path, from the start to a goal inclusive. There may be very many.
+	pub fn paths(&self) -> Vec<Vec<S>> {
+		let mut paths = Vec::new();
+		let mut stack: Vec<Vec<S>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+
+		while let Some(path) = stack.pop() {
+			let last = &path[path.len() - 1];
+
+			if *last == self.start {
+				paths.push(path.into_iter().rev().collect());
+				continue;
+			}
+
+			for previous in self.predecessors.get(last).into_iter().flatten() {
+				let mut extended = path.clone();
+				extended.push(previous.clone());
+				stack.push(extended);
+			}
+		}
+
+		paths
+	}
+}
+
+/// Every cheapest path to a goal, to every goal as cheap as the cheapest.
+pub fn shortest_paths<Sp: Space>(
+	space: &Sp,
+	start: Sp::State,
+	mut is_goal: impl FnMut(&Sp::State) -> bool,
+) -> Option<ShortestPaths<Sp::State, Sp::Cost>> {
+	let mut best = HashMap::from([(start.clone(), Sp::Cost::default())]);
+	let mut predecessors: HashMap<Sp::State, Vec<Sp::State>> = HashMap::new();
+	let mut goals = Vec::new();
+	let mut goal_cost = None;
+	let mut queue = BinaryHeap::from([Queued {
+		priority: Sp::Cost::default(),
+		cost: Sp::Cost::default(),
+		state: start.clone(),
+	}]);
+
+	while let Some(Queued { cost, state, .. }) = queue.pop() {
+		if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+			break;
+		}
+
+		if best[&state] < cost {
+			continue;
+		}
+
+		if is_goal(&state) {
+			goal_cost = Some(cost);
+			goals.push(state);
+			continue;
+		}
+
+		for (next, step) in space.successors(&state) {
+			let next_cost = cost + step;
+
+			match best.get(&next) {
+				Some(&known) if known < next_cost => {}
+				Some(&known) if known == next_cost => {
+					if next != start {
+						predecessors.entry(next).or_default().push(state.clone());
+					}
+				}
+				_ => {
+					best.insert(next.clone(), next_cost);
+					predecessors.insert(next.clone(), vec![state.clone()]);
+					queue.push(Queued {
+						priority: next_cost,
+						cost: next_cost,
+						state: next,
+					});
+				}
+			}
+		}
+	}
+
+	Some(ShortestPaths {
+		start,
+		cost: goal_cost?,
+		goals,
+		predecessors,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+
+	use super::{Path, Space, astar, bfs, bfs_distances, dijkstra, distances, shortest_paths};
+	use crate::util::grid::{Grid, Position};
+
+	/// A small weighted graph, in which the single move from `a` to `d` is the most expensive way there.
+	struct Graph(&'static [(char, char, u32)]);
+
+	const GRAPH: Graph = Graph(&[
+		('a', 'b', 1),
+		('a', 'c', 4),
+		('a', 'd', 10),
+		('a', 'e', 2),
+		('b', 'c', 1),
+		('c', 'd', 1),
+		('e', 'd', 1),
+		('f', 'a', 1),
+	]);
+
+	impl Space for Graph {
+		type State = char;
+		type Cost = u32;
+
+		fn successors(&self, &state: &char) -> impl IntoIterator<Item = (char, u32)> {
+			self
+				.0
+				.iter()
+				.filter(move |&&(from, _, _)| from == state)
+				.map(|&(_, to, cost)| (to, cost))
+		}
+	}
+
+	/// A maze of open cells (`true`) and walls, to be crossed to the bottom-right corner.
+	struct Maze(Grid<bool>);
+
+	impl Space for Maze {
+		type State = Position;
+		type Cost = usize;
+
+		fn successors(&self, &position: &Position) -> impl IntoIterator<Item = (Position, usize)> {
+			self
+				.0
+				.neighbors(position)
+				.filter(|&neighbor| self.0[neighbor])
+				.map(|neighbor| (neighbor, 1))
+		}
+
+		fn heuristic(&self, &(x, y): &Position) -> usize {
+			(self.0.width() - 1 - x) + (self.0.height() - 1 - y)
+		}
+	}
+
+	#[test]
+	fn finds_fewest_moves_and_cheapest_paths() {
+		assert_eq!(
+			Some(Path {
+				states: vec!['a', 'd'],
+				cost: 1
+			}),
+			bfs(&GRAPH, 'a', |&state| state == 'd')
+		);
+
+		let cheapest = dijkstra(&GRAPH, 'a', |&state| state == 'd').unwrap();
+		assert_eq!(3, cheapest.cost);
+		assert!(cheapest.states == ['a', 'b', 'c', 'd'] || cheapest.states == ['a', 'e', 'd']);
+
+		assert_eq!(None, dijkstra(&GRAPH, 'b', |&state| state == 'a'));
+		assert_eq!(None, bfs(&GRAPH, 'b', |&state| state == 'a'));
+	}
+
+	#[test]
+	fn maps_distances() {
+		let distances = distances(&GRAPH, 'a');
+		assert_eq!(5, distances.len());
+		assert_eq!(Some(&2), distances.get(&'c'));
+		assert_eq!(Some(&3), distances.get(&'d'));
+		assert_eq!(None, distances.get(&'f'));
+
+		let steps = bfs_distances(&GRAPH, 'f');
+		assert_eq!(Some(&2), steps.get(&'d'));
+		assert_eq!(Some(&0), steps.get(&'f'));
+	}
+
+	#[test]
+	fn enumerates_shortest_paths() {
+		let shortest = shortest_paths(&GRAPH, 'f', |&state| state == 'd').unwrap();
+		assert_eq!(4, shortest.cost());
+		assert_eq!(['d'], shortest.goals());
+
+		let mut paths = shortest.paths();
+		paths.sort();
+		assert_eq!(
+			vec![vec!['f', 'a', 'b', 'c', 'd'], vec!['f', 'a', 'e', 'd']],
+			paths
+		);
+		assert_eq!(
+			HashSet::from(['a', 'b', 'c', 'd', 'e', 'f']),
+			shortest.states()
+		);
+
+		let to_both = shortest_paths(&GRAPH, 'a', |&state| state == 'c' || state == 'e').unwrap();
+		assert_eq!(2, to_both.cost());
+		assert_eq!(2, to_both.paths().len());
+
+		assert!(shortest_paths(&GRAPH, 'd', |&state| state == 'a').is_none());
+	}
+
+	#[test]
+	fn astar_agrees_with_dijkstra() {
+		let maze = Maze(
+			Grid::parse(".#...\n.#.#.\n...#.\n##.#.\n.....", |c| match c {
+				'.' => Some(true),
+				'#' => Some(false),
+				_ => None,
+			})
+			.unwrap(),
+		);
+		let is_exit = |&position: &Position| position == (4, 4);
+
+		let guided = astar(&maze, (0, 0), is_exit).unwrap();
+		assert_eq!(8, guided.cost);
+		assert_eq!(guided.cost, dijkstra(&maze, (0, 0), is_exit).unwrap().cost);
+		assert_eq!(guided.cost, bfs(&maze, (0, 0), is_exit).unwrap().cost);
+		assert_eq!(9, guided.states.len());
+		assert!(guided.states.iter().all(|&position| maze.0[position]));
+	}
+}
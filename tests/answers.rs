@@ -0,0 +1,26 @@
+use std::{fs, process::Command};
+
+/// A typo in an answers file must fail the run, rather than quietly leaving the answers unverified.
+#[test]
+fn malformed_answers_fail_the_run() {
+	let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+	fs::create_dir_all(&dir).unwrap();
+	let input = dir.join("day01.txt");
+	fs::copy("inputs/2024/day01.example1.txt", &input).unwrap();
+	fs::write(dir.join("day01.answers"), "part_one 11\n").unwrap();
+
+	let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+		.arg("--input")
+		.arg(&input)
+		.args(["2024", "1"])
+		.output()
+		.unwrap();
+	fs::remove_dir_all(&dir).unwrap();
+
+	assert!(!output.status.success());
+	assert!(
+		String::from_utf8(output.stderr)
+			.unwrap()
+			.contains("malformed line 1")
+	);
+}
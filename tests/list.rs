@@ -0,0 +1,45 @@
+use core::time::Duration;
+use std::{
+	process::{Command, Output},
+	sync::mpsc,
+	thread,
+};
+
+/// Run `aoc` with `args`, failing if it doesn't finish within a minute.
+fn aoc(args: &[&str]) -> Output {
+	let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+	command.args(args);
+
+	let (sender, receiver) = mpsc::channel();
+	thread::spawn(move || sender.send(command.output()));
+
+	receiver
+		.recv_timeout(Duration::from_secs(60))
+		.unwrap_or_else(|_| panic!("aoc {} did not finish", args.join(" ")))
+		.unwrap()
+}
+
+/// Probing runs solvers to see which parts answer, and a solver that prints mustn't find stdout locked by the table.
+#[test]
+fn probes_a_solver_which_prints() {
+	// 2021's day 12 prints its cave system as a graph, which used to hang `list` when it went to stdout.
+	let output = aoc(&["list", "--probe", "2021/12"]);
+
+	assert!(output.status.success());
+	assert!(
+		String::from_utf8(output.stdout)
+			.unwrap()
+			.contains("1 registered solver(s)")
+	);
+}
+
+/// Without `--probe`, nothing is run, so a solver which never finishes is listed straight away.
+#[test]
+fn lists_without_running_solvers() {
+	let output = aoc(&["list", "2021/14"]);
+
+	assert!(output.status.success());
+	let stdout = String::from_utf8(output.stdout).unwrap();
+	assert!(stdout.contains("2021  14  PartSolve"));
+	assert!(!stdout.contains("part one"));
+}